@@ -0,0 +1,3 @@
+// Environment variable holding the shared secret for admin endpoints.
+pub(crate) const SECRET: &str = "ADMIN_SECRET";
+pub(crate) const HEADER: &str = "X-Admin-Secret";
@@ -0,0 +1,4 @@
+pub(crate) const ACTIVE: &str = "active";
+pub(crate) const WITHDRAWN: &str = "withdrawn";
+pub(crate) const CUT: &str = "cut";
+pub(crate) const SUSPENDED: &str = "suspended";
@@ -0,0 +1,2 @@
+pub(crate) const NOT_COMMISSIONER: &str = "Only the commissioner can delete a league";
+pub(crate) const NOT_ADMIN: &str = "Only an admin can update competitor status";
@@ -0,0 +1 @@
+pub(crate) const MULTIPLIER: f64 = 2.0;
@@ -0,0 +1,2 @@
+// Competitions before this one predate leagues and are left out of league listings.
+pub(crate) const FIRST_LEAGUE_COMPETITION_ID: i64 = 28;
@@ -0,0 +1,3 @@
+pub(crate) const PENDING: &str = "pending";
+pub(crate) const ACTIVE: &str = "active";
+pub(crate) const COMPLETE: &str = "complete";
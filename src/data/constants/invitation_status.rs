@@ -0,0 +1,3 @@
+pub(crate) const PENDING: &str = "pending";
+pub(crate) const ACCEPTED: &str = "accepted";
+pub(crate) const DECLINED: &str = "declined";
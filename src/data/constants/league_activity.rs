@@ -0,0 +1,8 @@
+pub(crate) const RENAME: &str = "rename";
+pub(crate) const LOGO: &str = "logo";
+pub(crate) const PRIVACY: &str = "privacy";
+pub(crate) const PASSCODE: &str = "passcode";
+pub(crate) const CAPACITY: &str = "capacity";
+pub(crate) const REMOVE_MEMBER: &str = "remove_member";
+pub(crate) const TRANSFER_COMMISSIONER: &str = "transfer_commissioner";
+pub(crate) const COMPETITOR_STATUS: &str = "competitor_status";
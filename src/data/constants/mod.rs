@@ -1 +1,18 @@
+pub mod admin;
+pub mod athlete_status;
+pub mod auth_error;
+pub mod captain;
+pub mod competition;
+pub mod draft_status;
+pub mod invitation_status;
+pub mod league_activity;
 pub mod ntfy;
+pub mod pick_action;
+pub mod pick_error;
+pub mod pick_source;
+pub mod podium_points;
+pub mod roster_move;
+pub mod snapshot_source;
+pub mod status_consequence;
+pub mod tournament_type;
+pub mod waiver_status;
@@ -0,0 +1,5 @@
+pub(crate) const SAVE: &str = "save";
+pub(crate) const SWAP: &str = "swap";
+pub(crate) const DELETE: &str = "delete";
+pub(crate) const INVALIDATE: &str = "invalidate";
+pub(crate) const REPLACE: &str = "replace";
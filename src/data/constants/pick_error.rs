@@ -0,0 +1,8 @@
+pub(crate) const NOT_IN_COMPETITION: &str = "NOT_IN_COMPETITION";
+pub(crate) const INVALID_POSITION: &str = "INVALID_POSITION";
+pub(crate) const INVALID_WORKOUT: &str = "INVALID_WORKOUT";
+pub(crate) const GENDER_NOT_ALLOWED: &str = "GENDER_NOT_ALLOWED";
+pub(crate) const DUPLICATE_COMPETITOR: &str = "DUPLICATE_COMPETITOR";
+pub(crate) const DUPLICATE_POSITION: &str = "DUPLICATE_POSITION";
+pub(crate) const DUPLICATE_RANK: &str = "DUPLICATE_RANK";
+pub(crate) const PICK_NOT_OWNED: &str = "PICK_NOT_OWNED";
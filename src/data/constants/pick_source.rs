@@ -0,0 +1,12 @@
+pub(crate) const TOP: &str = "POST /pick/top";
+pub(crate) const CONFIDENCE: &str = "POST /pick/confidence";
+pub(crate) const SHOTCALLER: &str = "POST /pick/shotcaller";
+pub(crate) const SALARY: &str = "POST /pick/salary";
+pub(crate) const SWAP: &str = "POST /swap-pick";
+pub(crate) const DELETE_TOP: &str = "DELETE /pick/top";
+pub(crate) const DELETE_SHOTCALLER: &str = "DELETE /pick/shotcaller";
+pub(crate) const TOP_LINEUP: &str = "PUT /lineup/top";
+pub(crate) const SHOTCALLER_LINEUP: &str = "PUT /lineup/shotcaller";
+pub(crate) const COMPETITOR_STATUS: &str = "PUT /competition/v1/competitor/status";
+pub(crate) const LEAVE_LEAGUE: &str = "DELETE /tournament-user";
+pub(crate) const REMOVE_MEMBER: &str = "DELETE /tournament/member";
@@ -0,0 +1,2 @@
+pub(crate) const EXACT: f64 = 10.0;
+pub(crate) const NEAR: f64 = 5.0;
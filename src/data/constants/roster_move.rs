@@ -0,0 +1,2 @@
+pub(crate) const WAIVER: &str = "waiver";
+pub(crate) const FREE_AGENT: &str = "free_agent";
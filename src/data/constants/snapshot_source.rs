@@ -0,0 +1,2 @@
+pub(crate) const SCORES: &str = "scores";
+pub(crate) const LOCK: &str = "lock";
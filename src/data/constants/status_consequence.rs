@@ -0,0 +1,3 @@
+pub(crate) const ZERO_POINTS: &str = "zero_points";
+pub(crate) const FREE_SWAP: &str = "free_swap";
+pub(crate) const AUTO_REPLACE: &str = "auto_replace";
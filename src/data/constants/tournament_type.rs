@@ -0,0 +1,8 @@
+pub(crate) const TOP_N: i64 = 1;
+pub(crate) const SHOTCALLER: i64 = 2;
+pub(crate) const SNAKE_DRAFT: i64 = 3;
+pub(crate) const AUCTION_DRAFT: i64 = 4;
+pub(crate) const SURVIVOR: i64 = 5;
+pub(crate) const PODIUM: i64 = 6;
+pub(crate) const CONFIDENCE: i64 = 7;
+pub(crate) const SALARY_CAP: i64 = 8;
@@ -0,0 +1,4 @@
+pub(crate) const PENDING: &str = "pending";
+pub(crate) const AWARDED: &str = "awarded";
+pub(crate) const FAILED: &str = "failed";
+pub(crate) const CANCELLED: &str = "cancelled";
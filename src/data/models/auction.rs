@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Auction {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub competition_id: i64,
+    pub status: String,
+    pub budget: i64,
+    pub bid_seconds: i64,
+    pub nomination_number: i64,
+    pub competitor_id: Option<i64>,
+    pub current_bid: i64,
+    pub high_bidder_id: Option<i64>,
+    pub bid_deadline: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Draft {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub competition_id: i64,
+    pub status: String,
+    pub pick_seconds: i64,
+    pub rounds: i64,
+    pub current_pick: i64,
+    pub pick_deadline: Option<DateTime<Utc>>,
+}
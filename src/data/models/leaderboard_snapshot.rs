@@ -0,0 +1,13 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LeaderboardSnapshot {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub tournament_user_id: i64,
+    pub ordinal: i64,
+    pub source: String,
+    pub points: f64,
+    pub rank: i64,
+    pub created_at: String,
+}
@@ -1,21 +1,31 @@
 pub mod app_user;
+pub mod auction;
 pub mod competition;
 pub mod competition_competitor;
 pub mod competitor;
 pub mod competitor_status;
 pub mod country;
 pub mod division;
+pub mod draft;
 pub mod elite_competitor;
 pub mod gender;
+pub mod leaderboard_snapshot;
 pub mod news;
 pub mod open_score;
 pub mod region;
 pub mod score;
+pub mod season;
+pub mod season_tournament;
+pub mod survivor_elimination;
 pub mod tournament;
+pub mod tournament_invitation;
 pub mod tournament_type;
 pub mod tournament_user_picks;
 pub mod tournament_users;
+pub mod tournament_waitlist;
 pub mod tournament_workouts;
+pub mod waiver_claim;
+pub mod waiver_period;
 pub mod workout;
 pub mod workout_stage_movement;
 pub mod workout_stages;
@@ -0,0 +1,9 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Season {
+    pub id: i64,
+    pub name: String,
+    pub commissioner_id: i64,
+    pub created_at: String,
+}
@@ -0,0 +1,9 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SeasonTournament {
+    pub season_id: i64,
+    pub tournament_id: i64,
+    pub competition_id: i64,
+    pub weight: f64,
+}
@@ -0,0 +1,8 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SurvivorElimination {
+    pub tournament_user_id: i64,
+    pub ordinal: i64,
+    pub points: Option<f64>,
+}
@@ -13,6 +13,7 @@ pub struct Tournament {
     pub commissioner_id: u64,
     pub entries: Option<u64>,
     pub pick_count: Option<i64>,
+    pub max_entries: Option<i64>,
 
     pub competition: Option<Competition>,
     pub tournament_type: Option<TournamentType>,
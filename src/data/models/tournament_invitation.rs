@@ -0,0 +1,11 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TournamentInvitation {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub inviter_id: i64,
+    pub invitee_id: i64,
+    pub status: String,
+    pub created_at: String,
+}
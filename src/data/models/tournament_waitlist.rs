@@ -0,0 +1,9 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TournamentWaitlist {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub user_id: i64,
+    pub created_at: String,
+}
@@ -0,0 +1,12 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WaiverClaim {
+    pub id: i64,
+    pub tournament_id: i64,
+    pub tournament_user_id: i64,
+    pub drop_competitor_id: i64,
+    pub add_competitor_id: i64,
+    pub status: String,
+    pub created_at: String,
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WaiverPeriod {
+    pub id: i64,
+    pub competition_id: i64,
+    pub ordinal: i64,
+    pub process_at: DateTime<Utc>,
+    pub is_processed: bool,
+}
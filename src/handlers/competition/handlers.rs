@@ -1,11 +1,15 @@
 use crate::{
-    handlers::competition::request_models::{CreateCompetitionCompetitor, GetCompetitor},
-    services::competition::CompetitionService,
+    data::constants::auth_error,
+    handlers::competition::request_models::{
+        CreateCompetitionCompetitor, GetCompetitor, UpdateCompetitorStatusRequest,
+    },
+    services::{competition::CompetitionService, competitor_status::CompetitorStatusService},
+    utils::admin::is_admin,
 };
 use actix_web::{
-    get, post,
+    get, post, put,
     web::{Json, Path, ServiceConfig},
-    HttpResponse, Responder,
+    HttpRequest, HttpResponse, Responder,
 };
 use validator::Validate;
 
@@ -14,7 +18,8 @@ pub fn configure(config: &mut ServiceConfig) {
         .service(get_competitors)
         .service(get_active_competitions)
         .service(get_active_beta_competitions)
-        .service(create_competittion_competitor);
+        .service(create_competittion_competitor)
+        .service(update_competitor_status);
 }
 
 #[get("/active/beta")]
@@ -79,3 +84,36 @@ pub async fn create_competittion_competitor(
             |_| HttpResponse::Ok().finish(),
         )
 }
+
+#[put("/competitor/status")]
+pub async fn update_competitor_status(
+    http_request: HttpRequest,
+    body: Json<UpdateCompetitorStatusRequest>,
+) -> impl Responder {
+    if !is_admin(&http_request) {
+        return HttpResponse::Forbidden().body(auth_error::NOT_ADMIN);
+    }
+
+    if body.validate().is_err() {
+        let message = format!(
+            "update_competitor_status: -> {:?}",
+            body.validate().unwrap_err()
+        );
+
+        return HttpResponse::BadRequest().body(message);
+    }
+
+    let req: UpdateCompetitorStatusRequest = body.into_inner();
+
+    CompetitorStatusService::update_competitor_status(
+        req.competition_id,
+        req.competitor_id,
+        req.status.as_str(),
+        req.consequence.as_deref(),
+    )
+    .await
+    .map_or_else(
+        |e| HttpResponse::BadRequest().body(e.to_string()),
+        |status| HttpResponse::Ok().json(status),
+    )
+}
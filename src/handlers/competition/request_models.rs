@@ -16,3 +16,16 @@ pub struct CreateCompetitionCompetitor {
     #[serde(rename = "competitorId")]
     pub competitor_id: i64,
 }
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct UpdateCompetitorStatusRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[validate(length(min = 1))]
+    pub status: String,
+    pub consequence: Option<String>,
+}
@@ -41,3 +41,27 @@ pub struct ActiveCompetition {
     #[serde(rename = "womenCutLine")]
     pub women_cut_line: Option<i64>,
 }
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AffectedPickResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "tournamentUserPickId")]
+    pub tournament_user_pick_id: i64,
+    #[serde(rename = "replacementCompetitorId")]
+    pub replacement_competitor_id: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CompetitorStatusResponse {
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    pub status: String,
+    pub consequence: Option<String>,
+    #[serde(rename = "affectedPicks")]
+    pub affected_picks: Vec<AffectedPickResponse>,
+}
@@ -0,0 +1,305 @@
+use crate::{
+    data::constants::ntfy,
+    handlers::draft::request_models::{
+        BidRequest, DraftPickRequest, DraftRequest, NominationRequest, RosterMoveRequest,
+        StartAuctionRequest, StartDraftPathRequest, StartDraftRequest, WaiverClaimPathRequest,
+    },
+    services::{auction::AuctionService, draft::DraftService, waiver::WaiverService},
+    utils::notification::spawn_notification,
+};
+use actix_web::{
+    delete, get, post, put,
+    web::{Json, Path, ServiceConfig},
+    HttpResponse, Responder,
+};
+use validator::Validate;
+
+pub fn configure(config: &mut ServiceConfig) {
+    config
+        .service(process_expired_drafts)
+        .service(get_draft)
+        .service(start_draft)
+        .service(make_draft_pick)
+        .service(process_expired_auctions)
+        .service(get_auction)
+        .service(start_auction)
+        .service(nominate_competitor)
+        .service(place_bid)
+        .service(process_waivers)
+        .service(get_waivers)
+        .service(submit_waiver_claim)
+        .service(cancel_waiver_claim)
+        .service(pick_up_free_agent);
+}
+
+#[post("/autopick")]
+pub(crate) async fn process_expired_drafts() -> impl Responder {
+    DraftService::process_expired_drafts().await.map_or_else(
+        |e| {
+            let message = format!("process_expired_drafts: -> {:?}", e);
+            spawn_notification(ntfy::ERROR.to_string(), message);
+
+            HttpResponse::InternalServerError().body("Error processing expired drafts")
+        },
+        |_| HttpResponse::Ok().finish(),
+    )
+}
+
+#[get("/{tournamentId}")]
+pub(crate) async fn get_draft(req: Path<DraftRequest>) -> impl Responder {
+    DraftService::get_draft(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("get_draft: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting draft")
+            },
+            |draft| HttpResponse::Ok().json(draft),
+        )
+}
+
+#[put("/{tournamentId}/{userId}/start")]
+pub(crate) async fn start_draft(
+    req: Path<StartDraftPathRequest>,
+    body: Json<StartDraftRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("start_draft: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid start draft request");
+    }
+
+    DraftService::start_draft(&req, &body).await.map_or_else(
+        |e| {
+            let error_message = format!("start_draft: {:?}: -> {:?}", req.tournament_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e)
+        },
+        |draft| HttpResponse::Ok().json(draft),
+    )
+}
+
+#[post("/{tournamentId}/pick")]
+pub(crate) async fn make_draft_pick(
+    req: Path<DraftRequest>,
+    body: Json<DraftPickRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("make_draft_pick: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid draft pick request");
+    }
+
+    DraftService::make_pick(req.tournament_id, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("make_draft_pick: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |draft| HttpResponse::Ok().json(draft),
+        )
+}
+
+#[post("/auction/close")]
+pub(crate) async fn process_expired_auctions() -> impl Responder {
+    AuctionService::process_expired_auctions()
+        .await
+        .map_or_else(
+            |e| {
+                let message = format!("process_expired_auctions: -> {:?}", e);
+                spawn_notification(ntfy::ERROR.to_string(), message);
+
+                HttpResponse::InternalServerError().body("Error processing expired auctions")
+            },
+            |_| HttpResponse::Ok().finish(),
+        )
+}
+
+#[get("/auction/{tournamentId}")]
+pub(crate) async fn get_auction(req: Path<DraftRequest>) -> impl Responder {
+    AuctionService::get_auction(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("get_auction: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting auction")
+            },
+            |auction| HttpResponse::Ok().json(auction),
+        )
+}
+
+#[put("/auction/{tournamentId}/{userId}/start")]
+pub(crate) async fn start_auction(
+    req: Path<StartDraftPathRequest>,
+    body: Json<StartAuctionRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("start_auction: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid start auction request");
+    }
+
+    AuctionService::start_auction(&req, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("start_auction: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |auction| HttpResponse::Ok().json(auction),
+        )
+}
+
+#[post("/auction/{tournamentId}/nominate")]
+pub(crate) async fn nominate_competitor(
+    req: Path<DraftRequest>,
+    body: Json<NominationRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("nominate_competitor: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid nomination request");
+    }
+
+    AuctionService::nominate(req.tournament_id, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("nominate_competitor: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |auction| HttpResponse::Ok().json(auction),
+        )
+}
+
+#[post("/auction/{tournamentId}/bid")]
+pub(crate) async fn place_bid(req: Path<DraftRequest>, body: Json<BidRequest>) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("place_bid: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid bid request");
+    }
+
+    AuctionService::place_bid(req.tournament_id, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("place_bid: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |auction| HttpResponse::Ok().json(auction),
+        )
+}
+
+#[post("/waivers/process")]
+pub(crate) async fn process_waivers() -> impl Responder {
+    WaiverService::process_waivers().await.map_or_else(
+        |e| {
+            let message = format!("process_waivers: -> {:?}", e);
+            spawn_notification(ntfy::ERROR.to_string(), message);
+
+            HttpResponse::InternalServerError().body("Error processing waivers")
+        },
+        |_| HttpResponse::Ok().finish(),
+    )
+}
+
+#[get("/waivers/{tournamentId}")]
+pub(crate) async fn get_waivers(req: Path<DraftRequest>) -> impl Responder {
+    WaiverService::get_waivers(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("get_waivers: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting waivers")
+            },
+            |waivers| HttpResponse::Ok().json(waivers),
+        )
+}
+
+#[post("/waivers/{tournamentId}/claim")]
+pub(crate) async fn submit_waiver_claim(
+    req: Path<DraftRequest>,
+    body: Json<RosterMoveRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("submit_waiver_claim: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid waiver claim request");
+    }
+
+    WaiverService::submit_claim(req.tournament_id, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("submit_waiver_claim: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |waivers| HttpResponse::Ok().json(waivers),
+        )
+}
+
+#[delete("/waivers/claim/{claimId}/{tournamentUserId}")]
+pub(crate) async fn cancel_waiver_claim(req: Path<WaiverClaimPathRequest>) -> impl Responder {
+    WaiverService::cancel_claim(&req).await.map_or_else(
+        |e| {
+            let error_message = format!("cancel_waiver_claim: {:?}: -> {:?}", req.claim_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e)
+        },
+        |waivers| HttpResponse::Ok().json(waivers),
+    )
+}
+
+#[post("/waivers/{tournamentId}/free-agent")]
+pub(crate) async fn pick_up_free_agent(
+    req: Path<DraftRequest>,
+    body: Json<RosterMoveRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("pick_up_free_agent: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid free agent request");
+    }
+
+    WaiverService::pick_up_free_agent(req.tournament_id, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("pick_up_free_agent: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |waivers| HttpResponse::Ok().json(waivers),
+        )
+}
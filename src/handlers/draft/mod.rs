@@ -0,0 +1,3 @@
+pub mod handlers;
+pub mod request_models;
+pub mod response_models;
@@ -0,0 +1,89 @@
+use serde_derive::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct DraftRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct StartDraftPathRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct StartDraftRequest {
+    #[validate(range(min = 10, max = 86400))]
+    #[serde(rename = "pickSeconds")]
+    pub pick_seconds: Option<i64>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct DraftPickRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct StartAuctionRequest {
+    #[validate(range(min = 10, max = 10000))]
+    pub budget: Option<i64>,
+    #[validate(range(min = 5, max = 3600))]
+    #[serde(rename = "bidSeconds")]
+    pub bid_seconds: Option<i64>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct NominationRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[validate(range(min = 1))]
+    pub amount: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct BidRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    pub amount: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct RosterMoveRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "dropCompetitorId")]
+    pub drop_competitor_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "addCompetitorId")]
+    pub add_competitor_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct WaiverClaimPathRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "claimId")]
+    pub claim_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+}
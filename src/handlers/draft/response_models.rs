@@ -0,0 +1,203 @@
+use crate::handlers::league::response_models::{LeagueAthletesResponse, LeaguePosition};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct DraftOrderEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub slot: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct DraftPickResponse {
+    #[serde(rename = "pickNumber")]
+    pub pick_number: i64,
+    pub round: i64,
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "isAutoPick")]
+    pub is_auto_pick: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct DraftResponse {
+    #[serde(rename = "draftId")]
+    pub draft_id: i64,
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub status: String,
+    #[serde(rename = "pickSeconds")]
+    pub pick_seconds: i64,
+    pub rounds: i64,
+    #[serde(rename = "currentPick")]
+    pub current_pick: i64,
+    #[serde(rename = "onTheClock")]
+    pub on_the_clock: Option<i64>,
+    #[serde(rename = "pickDeadline")]
+    pub pick_deadline: Option<String>,
+    pub order: Vec<DraftOrderEntry>,
+    pub picks: Vec<DraftPickResponse>,
+    pub available: Vec<LeagueAthletesResponse>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuctionRosterEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    pub price: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuctionMemberResponse {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub slot: i64,
+    pub spent: i64,
+    pub remaining: i64,
+    #[serde(rename = "maxBid")]
+    pub max_bid: i64,
+    #[serde(rename = "openSlots")]
+    pub open_slots: i64,
+    pub roster: Vec<AuctionRosterEntry>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuctionValueResponse {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "positionId")]
+    pub position_id: i64,
+    pub adp: f64,
+    #[serde(rename = "pickPercentage")]
+    pub pick_percentage: f64,
+    #[serde(rename = "suggestedValue")]
+    pub suggested_value: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuctionLotResponse {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "currentBid")]
+    pub current_bid: i64,
+    #[serde(rename = "highBidder")]
+    pub high_bidder: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuctionResponse {
+    #[serde(rename = "auctionId")]
+    pub auction_id: i64,
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub status: String,
+    pub budget: i64,
+    #[serde(rename = "bidSeconds")]
+    pub bid_seconds: i64,
+    #[serde(rename = "nominationNumber")]
+    pub nomination_number: i64,
+    pub nominator: Option<i64>,
+    pub deadline: Option<String>,
+    pub lot: Option<AuctionLotResponse>,
+    pub positions: Vec<LeaguePosition>,
+    pub members: Vec<AuctionMemberResponse>,
+    pub available: Vec<AuctionValueResponse>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RosterEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "positionId")]
+    pub position_id: i64,
+    #[serde(rename = "fromOrdinal")]
+    pub from_ordinal: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct WaiverPriorityEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub priority: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct WaiverClaimResponse {
+    #[serde(rename = "claimId")]
+    pub claim_id: i64,
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "dropCompetitorId")]
+    pub drop_competitor_id: i64,
+    #[serde(rename = "dropName")]
+    pub drop_name: String,
+    #[serde(rename = "addCompetitorId")]
+    pub add_competitor_id: i64,
+    #[serde(rename = "addName")]
+    pub add_name: String,
+    pub status: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct WaiverResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "isWaiverPeriod")]
+    pub is_waiver_period: bool,
+    #[serde(rename = "processAt")]
+    pub process_at: Option<String>,
+    #[serde(rename = "effectiveOrdinal")]
+    pub effective_ordinal: Option<i64>,
+    pub priority: Vec<WaiverPriorityEntry>,
+    pub claims: Vec<WaiverClaimResponse>,
+    pub rosters: Vec<RosterEntry>,
+    #[serde(rename = "freeAgents")]
+    pub free_agents: Vec<LeagueAthletesResponse>,
+}
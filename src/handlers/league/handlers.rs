@@ -1,15 +1,33 @@
 use crate::handlers::league::request_models::{
-    CreateTopPickRequest, DeleteShotCallerPickRequest, DeleteTournamentRequest,
-    DeleteTournamentUserRequest, SwapPickRequest,
+    CommissionerMemberRequest, CommissionerRequest, CompetitionSalariesRequest,
+    ConsensusMatchupRequest, CreateConfidencePickRequest, CreateInvitationRequest,
+    CreateSalaryPickRequest, CreateTopPickRequest, DeleteShotCallerPickRequest,
+    DeleteTournamentRequest, DeleteTournamentUserRequest, GlobalLeaderboardRequest,
+    InvitationRequest, LeagueActivityRequest, LineupOverlapRequest, MultiMatchupRequest,
+    PickTimelineRequest, SalaryLineupRequest, SaveShotCallerLineupRequest, SaveTopLineupRequest,
+    SetCaptainRequest, StandingsHistoryRequest, SurvivorStandingsRequest, SwapPickRequest,
+    UpdateLeagueCapacityRequest, UpdateLeagueLogoRequest, UpdateLeagueNameRequest,
+    UpdateLeaguePrivacyRequest, WinProbabilityRequest,
 };
+use crate::services::consensus::ConsensusService;
+use crate::services::global_leaderboard::GlobalLeaderboardService;
+use crate::services::hindsight::HindsightService;
+use crate::services::lineup::LineupService;
+use crate::services::pick_event::PickEventService;
+use crate::services::podium::PodiumService;
+use crate::services::projection::ProjectionService;
+use crate::services::salary::SalaryService;
+use crate::services::simulation::SimulationService;
+use crate::services::snapshot::SnapshotService;
+use crate::services::survivor::SurvivorService;
 use crate::{
-    data::constants::ntfy,
+    data::constants::{auth_error, ntfy},
     handlers::league::request_models::{
         CompetitionWorkoutRequest, CreateLeague, CreateShotCallerPickRequest, InsertScoresRequest,
         JoinLeague, LeaderboardMatchupRequest, LeagueAthletes, LeagueLeaderboardRequest,
         OpenLeague, UserLeaguePicksRequest, UserLeaguesRequest, WorkoutPredictionRequest,
     },
-    services::league::LeagueService,
+    services::league::{DeleteTournamentError, LeagueService},
     utils::notification::spawn_notification,
 };
 use actix_web::{
@@ -27,10 +45,19 @@ pub fn configure(config: &mut ServiceConfig) {
         .service(get_user_league_picks)
         .service(get_shot_caller_picks)
         .service(save_top_pick)
+        .service(save_confidence_pick)
+        .service(set_captain)
+        .service(save_salary_pick)
+        .service(save_top_lineup)
+        .service(save_shotcaller_lineup)
+        .service(get_salary_lineup)
+        .service(get_competition_salaries)
         .service(get_user_leagues)
+        .service(get_global_leaderboard)
         .service(get_league_leaderboard)
         .service(get_leaderboard_matchup)
         .service(get_leaderboard_shotcaller_matchup)
+        .service(get_leaderboard_podium_matchup)
         .service(get_workout_prediction)
         .service(save_shot_caller_pick)
         .service(create_league)
@@ -42,7 +69,30 @@ pub fn configure(config: &mut ServiceConfig) {
         .service(delete_shot_caller_pick)
         .service(delete_tournament_user)
         .service(delete_tournament)
-        .service(swap_pick);
+        .service(swap_pick)
+        .service(get_invitations)
+        .service(send_invitation)
+        .service(accept_invitation)
+        .service(decline_invitation)
+        .service(rename_league)
+        .service(update_league_logo)
+        .service(update_league_privacy)
+        .service(rotate_league_passcode)
+        .service(update_league_capacity)
+        .service(leave_waitlist)
+        .service(remove_league_member)
+        .service(transfer_commissioner)
+        .service(get_league_activity)
+        .service(get_survivor_standings)
+        .service(get_standings_history)
+        .service(get_win_probabilities)
+        .service(get_projected_standings)
+        .service(get_hindsight_lineup)
+        .service(get_consensus_matchup)
+        .service(get_lineup_uniqueness)
+        .service(get_lineup_overlap)
+        .service(get_multi_matchup)
+        .service(get_pick_timeline);
 }
 
 #[get("/open")]
@@ -106,6 +156,433 @@ pub(crate) async fn get_user_leagues(req: Query<UserLeaguesRequest>) -> impl Res
     )
 }
 
+#[get("/invitations")]
+pub(crate) async fn get_invitations(req: Query<UserLeaguesRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!("get_invitations: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid invitations request");
+    }
+
+    LeagueService::get_invitations(req.user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("get_invitations: {:?}: -> {:?}", req.user_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting invitations")
+            },
+            |invitations| HttpResponse::Ok().json(invitations),
+        )
+}
+
+#[post("/invitation")]
+pub(crate) async fn send_invitation(body: Json<CreateInvitationRequest>) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("send_invitation: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid invitation request");
+    }
+
+    LeagueService::send_invitation(&body.0).await.map_or_else(
+        |e| {
+            let error_message = format!("send_invitation: {:?}: -> {:?}", body.tournament_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e)
+        },
+        |_| HttpResponse::Ok().finish(),
+    )
+}
+
+#[put("/invitation/{invitationId}/{userId}/accept")]
+pub(crate) async fn accept_invitation(req: Path<InvitationRequest>) -> impl Responder {
+    LeagueService::accept_invitation(req.invitation_id, req.user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("accept_invitation: {:?}: -> {:?}", req.invitation_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |leagues| HttpResponse::Ok().json(leagues),
+        )
+}
+
+#[put("/invitation/{invitationId}/{userId}/decline")]
+pub(crate) async fn decline_invitation(req: Path<InvitationRequest>) -> impl Responder {
+    LeagueService::decline_invitation(req.invitation_id, req.user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("decline_invitation: {:?}: -> {:?}", req.invitation_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |leagues| HttpResponse::Ok().json(leagues),
+        )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/name")]
+pub(crate) async fn rename_league(
+    req: Path<CommissionerRequest>,
+    body: Json<UpdateLeagueNameRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("rename_league: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid rename league request");
+    }
+
+    LeagueService::rename_league(&req, &body.name)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("rename_league: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |league| HttpResponse::Ok().json(league),
+        )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/logo")]
+pub(crate) async fn update_league_logo(
+    req: Path<CommissionerRequest>,
+    body: Json<UpdateLeagueLogoRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!("update_league_logo: -> {:?}", body.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid league logo request");
+    }
+
+    LeagueService::update_league_logo(&req, &body.logo)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("update_league_logo: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |league| HttpResponse::Ok().json(league),
+        )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/privacy")]
+pub(crate) async fn update_league_privacy(
+    req: Path<CommissionerRequest>,
+    body: Json<UpdateLeaguePrivacyRequest>,
+) -> impl Responder {
+    LeagueService::update_league_privacy(&req, body.is_private)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("update_league_privacy: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |league| HttpResponse::Ok().json(league),
+        )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/passcode")]
+pub(crate) async fn rotate_league_passcode(req: Path<CommissionerRequest>) -> impl Responder {
+    LeagueService::rotate_league_passcode(&req)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "rotate_league_passcode: {:?}: -> {:?}",
+                    req.tournament_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |passcode| HttpResponse::Ok().body(passcode),
+        )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/capacity")]
+pub(crate) async fn update_league_capacity(
+    req: Path<CommissionerRequest>,
+    body: Json<UpdateLeagueCapacityRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!(
+            "update_league_capacity: -> {:?}",
+            body.validate().unwrap_err()
+        );
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid league capacity request");
+    }
+
+    LeagueService::update_league_capacity(&req, body.max_entries)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "update_league_capacity: {:?}: -> {:?}",
+                    req.tournament_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |league| HttpResponse::Ok().json(league),
+        )
+}
+
+#[delete("/waitlist/{tournamentId}/{userId}")]
+pub(crate) async fn leave_waitlist(req: Path<JoinLeague>) -> impl Responder {
+    LeagueService::leave_waitlist(&req).await.map_or_else(
+        |e| {
+            let error_message = format!("leave_waitlist: {:?}: -> {:?}", req.tournament_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().finish()
+        },
+        |leagues| HttpResponse::Ok().json(leagues),
+    )
+}
+
+#[delete("/tournament/{tournamentId}/{userId}/member/{tournamentUserId}")]
+pub(crate) async fn remove_league_member(req: Path<CommissionerMemberRequest>) -> impl Responder {
+    LeagueService::remove_league_member(&req).await.map_or_else(
+        |e| {
+            let error_message = format!(
+                "remove_league_member: {:?}: -> {:?}",
+                req.tournament_user_id, e
+            );
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e)
+        },
+        |_| HttpResponse::Ok().finish(),
+    )
+}
+
+#[put("/tournament/{tournamentId}/{userId}/commissioner/{tournamentUserId}")]
+pub(crate) async fn transfer_commissioner(req: Path<CommissionerMemberRequest>) -> impl Responder {
+    LeagueService::transfer_commissioner(&req)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "transfer_commissioner: {:?}: -> {:?}",
+                    req.tournament_user_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e)
+            },
+            |league| HttpResponse::Ok().json(league),
+        )
+}
+
+#[get("/{tournamentId}/activity")]
+pub(crate) async fn get_league_activity(req: Path<LeagueActivityRequest>) -> impl Responder {
+    LeagueService::get_league_activity(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_league_activity: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting league activity")
+            },
+            |activity| HttpResponse::Ok().json(activity),
+        )
+}
+
+#[get("/{tournamentId}/survivor")]
+pub(crate) async fn get_survivor_standings(req: Path<SurvivorStandingsRequest>) -> impl Responder {
+    SurvivorService::get_standings(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_survivor_standings: {:?}: -> {:?}",
+                    req.tournament_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting survivor standings")
+            },
+            |standings| HttpResponse::Ok().json(standings),
+        )
+}
+
+#[get("/{tournamentId}/history")]
+pub(crate) async fn get_standings_history(req: Path<StandingsHistoryRequest>) -> impl Responder {
+    SnapshotService::get_standings_history(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_standings_history: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting standings history")
+            },
+            |history| HttpResponse::Ok().json(history),
+        )
+}
+
+#[get("/{tournamentId}/probabilities")]
+pub(crate) async fn get_win_probabilities(
+    req: Path<LeagueLeaderboardRequest>,
+    query: Query<WinProbabilityRequest>,
+) -> impl Responder {
+    if query.validate().is_err() {
+        let message = format!(
+            "get_win_probabilities: -> {:?}",
+            query.validate().unwrap_err()
+        );
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid win probability request");
+    }
+
+    SimulationService::get_win_probabilities(req.tournament_id, &query)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_win_probabilities: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |probabilities| HttpResponse::Ok().json(probabilities),
+        )
+}
+
+#[get("/{tournamentId}/projection")]
+pub(crate) async fn get_projected_standings(req: Path<LeagueLeaderboardRequest>) -> impl Responder {
+    ProjectionService::get_projected_standings(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_projected_standings: {:?}: -> {:?}",
+                    req.tournament_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |projection| HttpResponse::Ok().json(projection),
+        )
+}
+
+#[get("/{tournamentId}/hindsight")]
+pub(crate) async fn get_hindsight_lineup(req: Path<LeagueLeaderboardRequest>) -> impl Responder {
+    HindsightService::get_hindsight_lineup(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_hindsight_lineup: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |hindsight| HttpResponse::Ok().json(hindsight),
+        )
+}
+
+#[get("/{tournamentId}/consensus/{userId}")]
+pub(crate) async fn get_consensus_matchup(req: Path<ConsensusMatchupRequest>) -> impl Responder {
+    ConsensusService::get_consensus_matchup(req.tournament_id, req.user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_consensus_matchup: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |matchup| HttpResponse::Ok().json(matchup),
+        )
+}
+
+#[get("/{tournamentId}/uniqueness")]
+pub(crate) async fn get_lineup_uniqueness(req: Path<LeagueLeaderboardRequest>) -> impl Responder {
+    ConsensusService::get_uniqueness(req.tournament_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_lineup_uniqueness: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |uniqueness| HttpResponse::Ok().json(uniqueness),
+        )
+}
+
+#[get("/{tournamentId}/overlap/{userId}/{competitorId}")]
+pub(crate) async fn get_lineup_overlap(req: Path<LineupOverlapRequest>) -> impl Responder {
+    ConsensusService::get_overlap(req.tournament_id, req.user_id, req.competitor_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_lineup_overlap: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting lineup overlap")
+            },
+            |overlap| HttpResponse::Ok().json(overlap),
+        )
+}
+
+#[get("/{tournamentId}/matchup")]
+pub(crate) async fn get_multi_matchup(
+    req: Path<LeagueLeaderboardRequest>,
+    query: Query<MultiMatchupRequest>,
+) -> impl Responder {
+    if query.validate().is_err() {
+        let message = format!("get_multi_matchup: -> {:?}", query.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid matchup request");
+    }
+
+    LeagueService::get_multi_matchup(req.tournament_id, &query)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("get_multi_matchup: {:?}: -> {:?}", req.tournament_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |matchup| HttpResponse::Ok().json(matchup),
+        )
+}
+
 #[post("/swap-pick")]
 pub(crate) async fn swap_pick(req: Json<SwapPickRequest>) -> impl Responder {
     LeagueService::swap_pick(&req.0).await.map_or_else(
@@ -162,12 +639,18 @@ pub(crate) async fn delete_tournament(req: Path<DeleteTournamentRequest>) -> imp
     LeagueService::delete_tournament(req.tournament_id, req.user_id)
         .await
         .map_or_else(
-            |e| {
-                let error_message =
-                    format!("delete_tournament: {:?}: -> {:?}", req.tournament_id, e);
-                spawn_notification(ntfy::ERROR.to_string(), error_message);
+            |e| match e {
+                // Someone other than the commissioner trying to delete isn't a server fault.
+                DeleteTournamentError::NotCommissioner => {
+                    HttpResponse::Forbidden().body(auth_error::NOT_COMMISSIONER)
+                }
+                DeleteTournamentError::Data(e) => {
+                    let error_message =
+                        format!("delete_tournament: {:?}: -> {:?}", req.tournament_id, e);
+                    spawn_notification(ntfy::ERROR.to_string(), error_message);
 
-                HttpResponse::BadRequest().finish()
+                    HttpResponse::BadRequest().finish()
+                }
             },
             |leagues| HttpResponse::Ok().json(leagues),
         )
@@ -205,12 +688,167 @@ pub(crate) async fn save_top_pick(req: Json<CreateTopPickRequest>) -> impl Respo
                 );
                 spawn_notification(ntfy::ERROR.to_string(), error_message);
 
-                HttpResponse::InternalServerError().finish()
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |_| HttpResponse::Ok().finish(),
+        )
+}
+
+#[post("/pick/confidence")]
+pub(crate) async fn save_confidence_pick(req: Json<CreateConfidencePickRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!("save_confidence_pick: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid confidence pick request");
+    }
+
+    LeagueService::save_confidence_pick(&req.0)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "save_confidence_pick: {:?}: -> {:?}",
+                    req.pick.tournament_user_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
             },
             |_| HttpResponse::Ok().finish(),
         )
 }
 
+#[put("/pick/captain")]
+pub(crate) async fn set_captain(req: Json<SetCaptainRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!("set_captain: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid captain request");
+    }
+
+    LeagueService::set_captain(&req.0).await.map_or_else(
+        |e| {
+            let error_message = format!("set_captain: {:?}: -> {:?}", req.tournament_user_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e.to_string())
+        },
+        |_| HttpResponse::Ok().finish(),
+    )
+}
+
+#[post("/pick/salary")]
+pub(crate) async fn save_salary_pick(req: Json<CreateSalaryPickRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!("save_salary_pick: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid salary pick request");
+    }
+
+    SalaryService::save_pick(&req.0).await.map_or_else(
+        |e| {
+            let error_message =
+                format!("save_salary_pick: {:?}: -> {:?}", req.tournament_user_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e.to_string())
+        },
+        |lineup| HttpResponse::Ok().json(lineup),
+    )
+}
+
+#[put("/lineup/top")]
+pub(crate) async fn save_top_lineup(req: Json<SaveTopLineupRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!("save_top_lineup: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid lineup request");
+    }
+
+    LineupService::save_top_lineup(&req.0).await.map_or_else(
+        |e| {
+            let error_message =
+                format!("save_top_lineup: {:?}: -> {:?}", req.tournament_user_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::BadRequest().body(e.to_string())
+        },
+        |picks| HttpResponse::Ok().json(picks),
+    )
+}
+
+#[put("/lineup/shotcaller")]
+pub(crate) async fn save_shotcaller_lineup(
+    req: Json<SaveShotCallerLineupRequest>,
+) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!(
+            "save_shotcaller_lineup: -> {:?}",
+            req.validate().unwrap_err()
+        );
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid lineup request");
+    }
+
+    LineupService::save_shotcaller_lineup(&req.0)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "save_shotcaller_lineup: {:?}: -> {:?}",
+                    req.tournament_user_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |picks| HttpResponse::Ok().json(picks),
+        )
+}
+
+#[get("/picks/salary/{tournamentUserId}")]
+pub(crate) async fn get_salary_lineup(req: Path<SalaryLineupRequest>) -> impl Responder {
+    SalaryService::get_lineup(req.tournament_user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_salary_lineup: {:?}: -> {:?}",
+                    req.tournament_user_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting salary lineup")
+            },
+            |lineup| HttpResponse::Ok().json(lineup),
+        )
+}
+
+#[get("/salaries/{competitionId}")]
+pub(crate) async fn get_competition_salaries(
+    req: Path<CompetitionSalariesRequest>,
+) -> impl Responder {
+    SalaryService::get_salaries(req.competition_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_competition_salaries: {:?}: -> {:?}",
+                    req.competition_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting competition salaries")
+            },
+            |salaries| HttpResponse::Ok().json(salaries),
+        )
+}
+
 #[post("/pick/shotcaller")]
 pub(crate) async fn save_shot_caller_pick(
     req: Json<CreateShotCallerPickRequest>,
@@ -225,7 +863,7 @@ pub(crate) async fn save_shot_caller_pick(
                 );
                 spawn_notification(ntfy::ERROR.to_string(), error_message);
 
-                HttpResponse::InternalServerError().finish()
+                HttpResponse::BadRequest().body(e.to_string())
             },
             |_| HttpResponse::Ok().finish(),
         )
@@ -267,6 +905,31 @@ pub(crate) async fn get_shot_caller_picks(req: Path<UserLeaguePicksRequest>) ->
         )
 }
 
+#[get("/leaderboard/global")]
+pub(crate) async fn get_global_leaderboard(req: Query<GlobalLeaderboardRequest>) -> impl Responder {
+    if req.0.validate().is_err() {
+        let message = format!(
+            "get_global_leaderboard: -> {:?}",
+            req.validate().unwrap_err()
+        );
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid global leaderboard request");
+    }
+
+    GlobalLeaderboardService::get_global_leaderboard(&req.0)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!("get_global_leaderboard: {:?}: -> {:?}", &req.0, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |leaderboard| HttpResponse::Ok().json(leaderboard),
+        )
+}
+
 #[get("/{tournamentId}/leaderboard")]
 pub(crate) async fn get_league_leaderboard(req: Path<LeagueLeaderboardRequest>) -> impl Responder {
     LeagueService::get_league_leaderboard_new(&req.tournament_id)
@@ -329,6 +992,26 @@ pub(crate) async fn get_leaderboard_shotcaller_matchup(
     )
 }
 
+#[get("/{tournamentId}/leaderboard/podium/{userId}/{competitorId}")]
+pub(crate) async fn get_leaderboard_podium_matchup(
+    req: Path<LeaderboardMatchupRequest>,
+) -> impl Responder {
+    PodiumService::get_podium_leaderboard_matchup(req.tournament_id, req.user_id, req.competitor_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_leaderboard_podium_matchup: {:?}: -> {:?}",
+                    req.tournament_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting leaderboard matchup")
+            },
+            |matchup| HttpResponse::Ok().json(matchup),
+        )
+}
+
 #[get("/prediction/{competitionId}/{ordinal}")]
 pub(crate) async fn get_workout_prediction(req: Path<WorkoutPredictionRequest>) -> impl Responder {
     LeagueService::get_workout_prediction(&req.competition_id, &req.ordinal)
@@ -447,3 +1130,28 @@ pub(crate) async fn update_adp() -> impl Responder {
         |_| HttpResponse::Ok().finish(),
     )
 }
+
+#[get("/{tournamentId}/timeline/{userId}")]
+pub(crate) async fn get_pick_timeline(req: Path<PickTimelineRequest>) -> impl Responder {
+    if req.validate().is_err() {
+        let message = format!("get_pick_timeline: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid pick timeline request");
+    }
+
+    PickEventService::get_timeline(req.tournament_id, req.user_id)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message = format!(
+                    "get_pick_timeline: {:?} - {:?}: -> {:?}",
+                    req.tournament_id, req.user_id, e
+                );
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::InternalServerError().body("Error getting pick timeline")
+            },
+            |timeline| HttpResponse::Ok().json(timeline),
+        )
+}
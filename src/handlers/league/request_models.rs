@@ -48,6 +48,15 @@ pub struct CreateTopPickRequest {
     pub tournament_position_id: i64,
 }
 
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CreateConfidencePickRequest {
+    #[serde(flatten)]
+    #[validate]
+    pub pick: CreateTopPickRequest,
+    #[validate(range(min = 1))]
+    pub confidence: i64,
+}
+
 #[derive(Deserialize, Validate, Clone, Debug)]
 pub struct NextPick {
     #[serde(rename = "tournamentPositionId")]
@@ -178,6 +187,9 @@ pub struct CreateLeague {
     pub passcode: Option<String>,
     #[serde(rename = "pickCount")]
     pub pick_count: Option<i64>,
+    #[validate(range(min = 2))]
+    #[serde(rename = "maxEntries")]
+    pub max_entries: Option<i64>,
 }
 
 #[derive(Deserialize, Validate, Clone, Debug)]
@@ -198,3 +210,241 @@ pub struct CompetitionWorkoutRequest {
     #[validate(range(min = 1))]
     pub ordinal: i64,
 }
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CreateInvitationRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[validate(length(min = 3))]
+    pub username: String,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct InvitationRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "invitationId")]
+    pub invitation_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CommissionerRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CommissionerMemberRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct UpdateLeagueNameRequest {
+    #[validate(length(min = 3))]
+    pub name: String,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct UpdateLeagueLogoRequest {
+    #[validate(url)]
+    pub logo: String,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct UpdateLeaguePrivacyRequest {
+    #[serde(rename = "isPrivate")]
+    pub is_private: bool,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct LeagueActivityRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct UpdateLeagueCapacityRequest {
+    #[validate(range(min = 2))]
+    #[serde(rename = "maxEntries")]
+    pub max_entries: Option<i64>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SurvivorStandingsRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CompetitionSalariesRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SalaryLineupRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CreateSalaryPickRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SetCaptainRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserPickId")]
+    pub tournament_user_pick_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct GlobalLeaderboardRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentTypeId")]
+    pub tournament_type_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100))]
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<i64>,
+    #[validate(range(min = 1, max = 25))]
+    pub window: Option<i64>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct StandingsHistoryRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct WinProbabilityRequest {
+    #[validate(range(min = 100, max = 10000))]
+    pub simulations: Option<i64>,
+    pub seed: Option<u64>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct ConsensusMatchupRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct LineupOverlapRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct MultiMatchupRequest {
+    #[validate(length(min = 1))]
+    #[serde(rename = "tournamentUserIds")]
+    pub tournament_user_ids: String,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct LineupTopPick {
+    #[validate(range(min = 1))]
+    pub rank: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SaveTopLineupRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate]
+    pub picks: Vec<LineupTopPick>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct LineupShotCallerPick {
+    #[validate(range(min = 1))]
+    #[serde(rename = "workoutId")]
+    pub workout_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SaveShotCallerLineupRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[validate]
+    pub picks: Vec<LineupShotCallerPick>,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct PickTimelineRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
@@ -32,6 +32,10 @@ pub struct OpenLeagueResponse {
     pub entries: u64,
     #[serde(rename = "pickCount")]
     pub pick_count: u64,
+    #[serde(rename = "maxEntries")]
+    pub max_entries: Option<i64>,
+    #[serde(rename = "remainingSpots")]
+    pub remaining_spots: Option<i64>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -44,6 +48,17 @@ pub struct LeaderboardEntry {
     pub points: f64,
     pub event_wins: i64,
     pub ordinal: i64,
+    pub events: Vec<LeaderboardEvent>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LeaderboardEvent {
+    pub ordinal: i64,
+    pub points: f64,
+    #[serde(rename = "totalPoints")]
+    pub total_points: f64,
+    pub rank: i64,
+    pub movement: i64,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -78,6 +93,8 @@ pub struct MatchupPick {
     pub is_suspended: bool,
     #[serde(rename = "isFinal")]
     pub is_final: bool,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -100,6 +117,8 @@ pub struct MatchupShotcallerPick {
     pub is_cut: bool,
     #[serde(rename = "isSuspended")]
     pub is_suspended: bool,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -189,6 +208,8 @@ pub struct CompetitionLeaderboardResponse {
     pub finishes: Vec<f64>,
     pub placement: i64,
     pub is_withdrawn: bool,
+    pub is_cut: bool,
+    pub is_suspended: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -209,6 +230,7 @@ pub struct LeaderboardShotCallerScoreData {
 pub struct LeaderboardPicks {
     pub competitor_id: i64,
     pub rank: i64,
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -292,6 +314,30 @@ pub struct UserLeaguesResponse {
     #[serde(rename = "pickCount")]
     pub pick_count: Option<i64>,
     pub positions: Vec<LeaguePosition>,
+    #[serde(rename = "invitationId")]
+    pub invitation_id: Option<i64>,
+    #[serde(rename = "maxEntries")]
+    pub max_entries: Option<i64>,
+    #[serde(rename = "waitlistPosition")]
+    pub waitlist_position: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LeagueInvitationResponse {
+    pub id: i64,
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub tournament: String,
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    pub competition: String,
+    pub logo: String,
+    #[serde(rename = "tournamentTypeId")]
+    pub tournament_type_id: i64,
+    #[serde(rename = "invitedBy")]
+    pub invited_by: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -304,6 +350,9 @@ pub struct UserLeaguesTopPicksDataResponse {
     pub id: i64,
     #[serde(rename = "tournamentPositionId")]
     pub tournament_position_id: u64,
+    pub confidence: Option<i64>,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -315,6 +364,8 @@ pub struct UserLeaguesPicksDataResponse {
     pub id: i64,
     #[serde(rename = "tournamentPositionId")]
     pub tournament_position_id: u64,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -325,6 +376,9 @@ pub struct PickCompetitor {
     pub id: i64,
     #[serde(rename = "tournamentPositionId")]
     pub tournament_position_id: i64,
+    pub confidence: Option<i64>,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -333,6 +387,9 @@ pub struct CompetitorPick {
     pub competitor_id: u64,
     pub id: i64,
     pub rank: i64,
+    pub confidence: Option<i64>,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -344,6 +401,8 @@ pub struct PositionPicks {
     pub id: i64,
     #[serde(rename = "workoutId")]
     pub workout_id: i64,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -483,3 +542,566 @@ pub struct PropBet {
     pub description: Option<String>,
     pub options: Vec<PropBetOption>,
 }
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CompetitionEntryData {
+    pub competitor_id: i64,
+    pub gender_id: i64,
+    pub position_id: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PickSlotData {
+    pub tournament_user_id: i64,
+    pub tournament_position_id: i64,
+    pub workout_id: Option<i64>,
+    pub rank: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PickEventData {
+    pub action: String,
+    pub source: String,
+    pub slot: PickSlotData,
+    pub previous_competitor_id: Option<i64>,
+    pub competitor_id: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AffectedPickData {
+    pub tournament_user_pick_id: i64,
+    pub tournament_id: i64,
+    pub tournament_type_id: i64,
+    pub tournament_user_id: i64,
+    pub user_id: i64,
+    pub tournament_position_id: i64,
+    pub workout_id: Option<i64>,
+    pub rank: Option<i64>,
+    pub is_workout_active: bool,
+}
+
+// Everything a status change writes, applied together in one transaction.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CompetitorStatusChangeData {
+    pub competition_id: i64,
+    pub competitor_id: i64,
+    pub is_withdrawn: bool,
+    pub is_cut: bool,
+    pub is_suspended: bool,
+    pub consequence: Option<String>,
+    pub invalidated_pick_ids: Vec<i64>,
+    // (tournament_user_pick_id, replacement competitor_id)
+    pub replacements: Vec<(i64, i64)>,
+    pub events: Vec<PickEventData>,
+    pub activities: Vec<LeagueActivityData>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TournamentMemberData {
+    pub tournament_user_id: i64,
+    pub tournament_id: i64,
+    pub user_id: i64,
+}
+
+// An entry for a league's activity feed, written alongside the change it records.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LeagueActivityData {
+    pub tournament_id: i64,
+    pub user_id: i64,
+    pub action: String,
+    pub detail: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LeagueActivityResponse {
+    pub id: i64,
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    pub username: String,
+    pub action: String,
+    pub detail: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SurvivorPickData {
+    pub tournament_user_id: i64,
+    pub workout_id: i64,
+    pub ordinal: i64,
+    pub competitor_id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub points: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SurvivorPickResponse {
+    pub ordinal: i64,
+    #[serde(rename = "workoutId")]
+    pub workout_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub points: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SurvivorStandingEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    #[serde(rename = "isAlive")]
+    pub is_alive: bool,
+    #[serde(rename = "eliminatedOrdinal")]
+    pub eliminated_ordinal: Option<i64>,
+    #[serde(rename = "eventsSurvived")]
+    pub events_survived: i64,
+    pub points: f64,
+    pub rank: i64,
+    pub picks: Vec<SurvivorPickResponse>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SurvivorStandingsResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "decidedEvents")]
+    pub decided_events: Vec<i64>,
+    #[serde(rename = "aliveCount")]
+    pub alive_count: i64,
+    pub standings: Vec<SurvivorStandingEntry>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PodiumPick {
+    #[serde(rename = "workoutId")]
+    pub workout_id: i64,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+    pub slot: i64,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub placement: Option<i64>,
+    pub points: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PodiumMatchupDetail {
+    pub points: f64,
+    #[serde(rename = "exactPicks")]
+    pub exact_picks: i64,
+    pub picks: Vec<PodiumPick>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LeaderboardMatchupPodiumResponse {
+    #[serde(rename = "userMatchup")]
+    pub user_matchup: PodiumMatchupDetail,
+    pub workouts: Vec<WorkoutResponse>,
+    #[serde(rename = "competitorMatchup")]
+    pub competitor_matchup: PodiumMatchupDetail,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SalaryData {
+    pub competitor_id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub gender_id: i64,
+    pub position_id: Option<i64>,
+    pub adp: f64,
+    pub pick_percentage: f64,
+    pub is_withdrawn: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CompetitorSalaryResponse {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "positionId")]
+    pub position_id: Option<i64>,
+    pub adp: f64,
+    #[serde(rename = "pickPercentage")]
+    pub pick_percentage: f64,
+    pub salary: i64,
+    #[serde(rename = "isWithdrawn")]
+    pub is_withdrawn: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SalaryPickData {
+    pub id: i64,
+    pub competitor_id: i64,
+    pub tournament_position_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SalaryLineupPick {
+    pub id: i64,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub salary: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SalaryLineupResponse {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "salaryCap")]
+    pub salary_cap: i64,
+    #[serde(rename = "salaryUsed")]
+    pub salary_used: i64,
+    #[serde(rename = "salaryRemaining")]
+    pub salary_remaining: i64,
+    pub picks: Vec<SalaryLineupPick>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct GlobalLeaderboardEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub tournament: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub points: f64,
+    pub event_wins: i64,
+    pub ordinal: i64,
+    pub position: i64,
+    pub percentile: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct GlobalLeaderboardResponse {
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    #[serde(rename = "tournamentTypeId")]
+    pub tournament_type_id: i64,
+    pub total: i64,
+    pub page: i64,
+    #[serde(rename = "pageSize")]
+    pub page_size: i64,
+    pub leaderboard: Vec<GlobalLeaderboardEntry>,
+    pub user: Option<GlobalLeaderboardEntry>,
+    #[serde(rename = "aroundUser")]
+    pub around_user: Vec<GlobalLeaderboardEntry>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StandingsSnapshot {
+    pub ordinal: i64,
+    pub source: String,
+    pub points: f64,
+    pub rank: i64,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StandingsHistoryEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub history: Vec<StandingsSnapshot>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StandingsHistoryResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub standings: Vec<StandingsHistoryEntry>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CompetitorProjection {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "currentPlacement")]
+    pub current_placement: i64,
+    #[serde(rename = "expectedPlacement")]
+    pub expected_placement: f64,
+    #[serde(rename = "placementProbabilities")]
+    pub placement_probabilities: Vec<f64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MemberWinProbability {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    #[serde(rename = "currentPoints")]
+    pub current_points: f64,
+    #[serde(rename = "expectedPoints")]
+    pub expected_points: f64,
+    #[serde(rename = "winProbability")]
+    pub win_probability: f64,
+    #[serde(rename = "top3Probability")]
+    pub top_3_probability: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct WinProbabilityResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub simulations: i64,
+    pub seed: u64,
+    #[serde(rename = "remainingEvents")]
+    pub remaining_events: i64,
+    pub competitors: Vec<CompetitorProjection>,
+    pub members: Vec<MemberWinProbability>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ProjectedCompetitor {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: i64,
+    #[serde(rename = "currentPoints")]
+    pub current_points: f64,
+    #[serde(rename = "currentPlacement")]
+    pub current_placement: i64,
+    #[serde(rename = "projectedPoints")]
+    pub projected_points: f64,
+    #[serde(rename = "projectedPlacement")]
+    pub projected_placement: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ProjectedStandingEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    #[serde(rename = "currentPoints")]
+    pub current_points: f64,
+    #[serde(rename = "currentRank")]
+    pub current_rank: i64,
+    #[serde(rename = "projectedPoints")]
+    pub projected_points: f64,
+    #[serde(rename = "projectedRank")]
+    pub projected_rank: i64,
+    pub movement: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ProjectedStandingsResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "remainingEvents")]
+    pub remaining_events: i64,
+    pub competitors: Vec<ProjectedCompetitor>,
+    pub standings: Vec<ProjectedStandingEntry>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct HindsightScoreData {
+    pub competitor_id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub position_id: Option<i64>,
+    pub ordinal: i64,
+    pub points: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct HindsightPick {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    #[serde(rename = "genderId")]
+    pub gender_id: Option<i64>,
+    pub rank: Option<i64>,
+    #[serde(rename = "workoutId")]
+    pub workout_id: Option<i64>,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: Option<i64>,
+    pub points: f64,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct HindsightMember {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: u64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub points: f64,
+    #[serde(rename = "pointsLeft")]
+    pub points_left: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct HindsightResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "optimalPoints")]
+    pub optimal_points: f64,
+    #[serde(rename = "optimalLineup")]
+    pub optimal_lineup: Vec<HindsightPick>,
+    pub members: Vec<HindsightMember>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ConsensusPickData {
+    pub competitor_id: i64,
+    pub pick_percentage: f64,
+    pub average_rank: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MemberPickData {
+    pub tournament_user_id: i64,
+    pub competitor_id: i64,
+    pub workout_id: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LineupUniquenessEntry {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub picks: i64,
+    pub uniqueness: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct LineupOverlapResponse {
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "sharedPicks")]
+    pub shared_picks: i64,
+    #[serde(rename = "totalPicks")]
+    pub total_picks: i64,
+    #[serde(rename = "sharedCompetitorIds")]
+    pub shared_competitor_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MultiMatchupPick {
+    #[serde(rename = "competitorId")]
+    pub competitor_id: i64,
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[serde(rename = "lastName")]
+    pub last_name: String,
+    pub points: f64,
+    #[serde(rename = "isCaptain")]
+    pub is_captain: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MultiMatchupSlot {
+    #[serde(rename = "genderId")]
+    pub gender_id: Option<i64>,
+    pub rank: Option<i64>,
+    #[serde(rename = "workoutId")]
+    pub workout_id: Option<i64>,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: Option<i64>,
+    pub picks: Vec<Option<MultiMatchupPick>>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MultiMatchupMember {
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub points: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MultiMatchupResponse {
+    #[serde(rename = "tournamentTypeId")]
+    pub tournament_type_id: i64,
+    #[serde(rename = "lockedEvents")]
+    pub locked_events: u64,
+    pub workouts: Vec<WorkoutResponse>,
+    pub members: Vec<MultiMatchupMember>,
+    pub slots: Vec<MultiMatchupSlot>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PickEventResponse {
+    pub id: i64,
+    pub action: String,
+    pub source: String,
+    #[serde(rename = "previousCompetitorId")]
+    pub previous_competitor_id: Option<i64>,
+    #[serde(rename = "previousCompetitorName")]
+    pub previous_competitor_name: Option<String>,
+    #[serde(rename = "competitorId")]
+    pub competitor_id: Option<i64>,
+    #[serde(rename = "competitorName")]
+    pub competitor_name: Option<String>,
+    #[serde(rename = "tournamentPositionId")]
+    pub tournament_position_id: i64,
+    #[serde(rename = "workoutId")]
+    pub workout_id: Option<i64>,
+    #[serde(rename = "workoutName")]
+    pub workout_name: Option<String>,
+    pub rank: Option<i64>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PickTimelineResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[serde(rename = "tournamentUserId")]
+    pub tournament_user_id: i64,
+    pub events: Vec<PickEventResponse>,
+}
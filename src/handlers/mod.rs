@@ -3,7 +3,9 @@ pub mod ads;
 pub mod athlete;
 pub mod competition;
 pub mod crossfit;
+pub mod draft;
 pub mod league;
 pub mod news;
 pub mod open;
 pub mod props;
+pub mod season;
@@ -0,0 +1,103 @@
+use crate::{
+    data::constants::ntfy,
+    handlers::season::request_models::{
+        CreateSeasonRequest, SeasonCommissionerRequest, SeasonRequest, SeasonTournamentPathRequest,
+        SeasonTournamentRequest,
+    },
+    services::season::SeasonService,
+    utils::notification::spawn_notification,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Json, Path, ServiceConfig},
+    HttpResponse, Responder,
+};
+use validator::Validate;
+
+pub fn configure(config: &mut ServiceConfig) {
+    config
+        .service(create_season)
+        .service(get_season)
+        .service(add_season_tournament)
+        .service(remove_season_tournament);
+}
+
+#[post("/")]
+pub(crate) async fn create_season(req: Json<CreateSeasonRequest>) -> impl Responder {
+    if req.validate().is_err() {
+        let message = format!("create_season: -> {:?}", req.validate().unwrap_err());
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid create season request");
+    }
+
+    SeasonService::create_season(&req).await.map_or_else(
+        |e| {
+            let error_message = format!("create_season: {:?}: -> {:?}", req.user_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::InternalServerError().body("Error creating season")
+        },
+        |season| HttpResponse::Ok().json(season),
+    )
+}
+
+#[get("/{seasonId}")]
+pub(crate) async fn get_season(req: Path<SeasonRequest>) -> impl Responder {
+    SeasonService::get_season(req.season_id).await.map_or_else(
+        |e| {
+            let error_message = format!("get_season: {:?}: -> {:?}", req.season_id, e);
+            spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+            HttpResponse::InternalServerError().body("Error getting season")
+        },
+        |season| HttpResponse::Ok().json(season),
+    )
+}
+
+#[post("/{seasonId}/{userId}/tournament")]
+pub(crate) async fn add_season_tournament(
+    req: Path<SeasonCommissionerRequest>,
+    body: Json<SeasonTournamentRequest>,
+) -> impl Responder {
+    if body.validate().is_err() {
+        let message = format!(
+            "add_season_tournament: -> {:?}",
+            body.validate().unwrap_err()
+        );
+        spawn_notification(ntfy::ERROR.to_string(), message);
+
+        return HttpResponse::BadRequest().body("Invalid season tournament request");
+    }
+
+    SeasonService::add_season_tournament(&req, &body)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("add_season_tournament: {:?}: -> {:?}", req.season_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |season| HttpResponse::Ok().json(season),
+        )
+}
+
+#[delete("/{seasonId}/{userId}/tournament/{tournamentId}")]
+pub(crate) async fn remove_season_tournament(
+    req: Path<SeasonTournamentPathRequest>,
+) -> impl Responder {
+    SeasonService::remove_season_tournament(&req)
+        .await
+        .map_or_else(
+            |e| {
+                let error_message =
+                    format!("remove_season_tournament: {:?}: -> {:?}", req.season_id, e);
+                spawn_notification(ntfy::ERROR.to_string(), error_message);
+
+                HttpResponse::BadRequest().body(e.to_string())
+            },
+            |season| HttpResponse::Ok().json(season),
+        )
+}
@@ -0,0 +1,50 @@
+use serde_derive::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SeasonRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "seasonId")]
+    pub season_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct CreateSeasonRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SeasonCommissionerRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "seasonId")]
+    pub season_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SeasonTournamentPathRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "seasonId")]
+    pub season_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+}
+
+#[derive(Deserialize, Validate, Clone, Debug)]
+pub struct SeasonTournamentRequest {
+    #[validate(range(min = 1))]
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    #[validate(range(min = 0.0, max = 10.0))]
+    pub weight: f64,
+}
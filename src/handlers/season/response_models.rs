@@ -0,0 +1,52 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SeasonMemberData {
+    pub tournament_id: i64,
+    pub tournament_user_id: i64,
+    pub user_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SeasonCompetitionResponse {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub tournament: String,
+    #[serde(rename = "competitionId")]
+    pub competition_id: i64,
+    pub competition: String,
+    pub weight: f64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SeasonResult {
+    #[serde(rename = "tournamentId")]
+    pub tournament_id: i64,
+    pub points: f64,
+    #[serde(rename = "weightedPoints")]
+    pub weighted_points: f64,
+    pub rank: i64,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SeasonStandingEntry {
+    #[serde(rename = "userId")]
+    pub user_id: i64,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub avatar: String,
+    pub points: f64,
+    pub rank: i64,
+    pub results: Vec<SeasonResult>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SeasonResponse {
+    #[serde(rename = "seasonId")]
+    pub season_id: i64,
+    pub name: String,
+    #[serde(rename = "commissionerId")]
+    pub commissioner_id: i64,
+    pub competitions: Vec<SeasonCompetitionResponse>,
+    pub standings: Vec<SeasonStandingEntry>,
+}
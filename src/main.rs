@@ -3,9 +3,10 @@ use actix_web::{web::scope, App, HttpServer};
 use fantasy_api::handlers::{
     account::handlers as account_handlers, ads::handlers as ad_handlers,
     athlete::handlers as athlete_handlers, competition::handlers as competition_handlers,
-    crossfit::handlers as crossfit_handlers, league::handlers as league_handlers,
+    crossfit::handlers as crossfit_handlers, draft::handlers as draft_handlers,
+    league::handlers as league_handlers,
     news::handlers as news_handlers, open::handlers as open_handlers,
-    props::handlers as prop_handlers,
+    props::handlers as prop_handlers, season::handlers as season_handlers,
 };
 use log::info;
 
@@ -32,9 +33,11 @@ async fn main() -> std::io::Result<()> {
             .service(scope("/athlete/v1").configure(athlete_handlers::configure))
             .service(scope("/competition/v1").configure(competition_handlers::configure))
             .service(scope("/league/v1").configure(league_handlers::configure))
+            .service(scope("/draft/v1").configure(draft_handlers::configure))
             .service(scope("/news/v1").configure(news_handlers::configure))
             .service(scope("/ads/v1").configure(ad_handlers::configure))
             .service(scope("/props/v1").configure(prop_handlers::configure))
+            .service(scope("/season/v1").configure(season_handlers::configure))
             .service(scope("/crossfit/v1").configure(crossfit_handlers::configure))
             .service(scope("/open/v1").configure(open_handlers::configure))
     })
@@ -9,12 +9,14 @@ use crate::{
 pub struct AppUserRepository;
 
 impl AppUserRepository {
-    pub async fn fetch_email_by_username(username: String) -> Result<String, Error> {
+    // Returns (id, email).
+    pub async fn fetch_user_by_username(username: String) -> Result<(i64, String), Error> {
         let pool = DataClient::connect().await?;
 
         let res = sqlx::query(
             "
             SELECT
+                id,
                 email
             FROM
                 app_user
@@ -26,9 +28,7 @@ impl AppUserRepository {
         .fetch_one(&pool)
         .await?;
 
-        let email = res.get("email");
-
-        return Ok(email);
+        Ok((res.get("id"), res.get("email")))
     }
 
     pub async fn fetch_is_new_username_valid(username: String) -> Result<bool, Error> {
@@ -0,0 +1,410 @@
+use crate::data::constants::draft_status;
+use crate::data::data_client::DataClient;
+use crate::data::models::auction::Auction;
+use crate::handlers::draft::response_models::{AuctionRosterEntry, DraftOrderEntry};
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct AuctionRepository;
+
+impl AuctionRepository {
+    fn map_auction(row: PgRow) -> Auction {
+        Auction {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            competition_id: row.get("competition_id"),
+            status: row.get("status"),
+            budget: row.get("budget"),
+            bid_seconds: row.get("bid_seconds"),
+            nomination_number: row.get("nomination_number"),
+            competitor_id: row.get("competitor_id"),
+            current_bid: row.get("current_bid"),
+            high_bidder_id: row.get("high_bidder_id"),
+            bid_deadline: row.get("bid_deadline"),
+        }
+    }
+
+    pub async fn fetch_auction(tournament_id: i64) -> Result<Auction, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                a.id,
+                a.tournament_id,
+                t.competition_id,
+                a.status,
+                a.budget,
+                a.bid_seconds,
+                a.nomination_number,
+                a.competitor_id,
+                a.current_bid,
+                a.high_bidder_id,
+                a.bid_deadline
+            FROM
+                auction a
+            JOIN
+                tournament t
+                ON t.id = a.tournament_id
+            WHERE
+                a.tournament_id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .map(Self::map_auction)
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_expired_auctions() -> Result<Vec<Auction>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                a.id,
+                a.tournament_id,
+                t.competition_id,
+                a.status,
+                a.budget,
+                a.bid_seconds,
+                a.nomination_number,
+                a.competitor_id,
+                a.current_bid,
+                a.high_bidder_id,
+                a.bid_deadline
+            FROM
+                auction a
+            JOIN
+                tournament t
+                ON t.id = a.tournament_id
+            WHERE
+                a.status = $1
+                AND a.bid_deadline < $2::timestamptz
+            ",
+        )
+        .bind(draft_status::ACTIVE)
+        .bind(format!("{}", chrono::Utc::now()))
+        .map(Self::map_auction)
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_auction_order(auction_id: i64) -> Result<Vec<DraftOrderEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                ao.tournament_user_id,
+                ao.slot,
+                tu.display_name,
+                au.username,
+                au.profile_url
+            FROM
+                auction_order ao
+            JOIN
+                tournament_users tu
+                ON tu.id = ao.tournament_user_id
+            JOIN
+                app_user au
+                ON au.id = tu.user_id
+            WHERE
+                ao.auction_id = $1
+            ORDER BY
+                ao.slot
+            ",
+        )
+        .bind(auction_id)
+        .map(|row: PgRow| DraftOrderEntry {
+            tournament_user_id: row.get("tournament_user_id"),
+            display_name: row
+                .get::<Option<String>, _>("display_name")
+                .unwrap_or(row.get("username")),
+            avatar: row.get("profile_url"),
+            slot: row.get("slot"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_auction_picks(auction_id: i64) -> Result<Vec<AuctionRosterEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                ap.tournament_user_id,
+                ap.tournament_position_id,
+                ap.competitor_id,
+                ap.price,
+                c.first_name,
+                c.last_name,
+                c.gender_id
+            FROM
+                auction_picks ap
+            JOIN
+                competitor c
+                ON c.id = ap.competitor_id
+            WHERE
+                ap.auction_id = $1
+            ORDER BY
+                ap.id
+            ",
+        )
+        .bind(auction_id)
+        .map(|row: PgRow| AuctionRosterEntry {
+            tournament_user_id: row.get("tournament_user_id"),
+            tournament_position_id: row.get("tournament_position_id"),
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            gender_id: row.get("gender_id"),
+            price: row.get("price"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn insert_auction(
+        tournament_id: i64,
+        budget: i64,
+        bid_seconds: i64,
+    ) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            INSERT INTO auction (tournament_id, status, budget, bid_seconds, nomination_number, current_bid)
+            VALUES ($1, $2, $3, $4, 0, 0)
+            RETURNING id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(draft_status::PENDING)
+        .bind(budget)
+        .bind(bid_seconds)
+        .fetch_one(&pool)
+        .await?;
+
+        let id = res.get("id");
+
+        Ok(id)
+    }
+
+    pub async fn insert_auction_order(auction_id: i64, tournament_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO auction_order (auction_id, tournament_user_id, slot)
+            SELECT
+                $1,
+                tu.id,
+                ROW_NUMBER() OVER (ORDER BY RANDOM())
+            FROM
+                tournament_users tu
+            WHERE
+                tu.tournament_id = $2
+            ",
+        )
+        .bind(auction_id)
+        .bind(tournament_id)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // A lot closed twice by overlapping requests only awards the athlete once.
+    pub async fn update_auction_settings(
+        auction_id: i64,
+        status: &str,
+        budget: i64,
+        bid_seconds: i64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            UPDATE auction
+            SET status = $2, budget = $3, bid_seconds = $4
+            WHERE id = $1
+            ",
+        )
+        .bind(auction_id)
+        .bind(status)
+        .bind(budget)
+        .bind(bid_seconds)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Moves past `nomination_number` only if it's still the current nomination, returns false
+    // when another request already did.
+    pub async fn update_auction_nomination(
+        auction_id: i64,
+        nomination_number: i64,
+        status: &str,
+        bid_deadline: Option<DateTime<Utc>>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE auction
+            SET
+                status = $3,
+                nomination_number = $2 + 1,
+                bid_deadline = $4::timestamptz,
+                competitor_id = NULL,
+                current_bid = 0,
+                high_bidder_id = NULL
+            WHERE id = $1
+                AND nomination_number = $2
+            ",
+        )
+        .bind(auction_id)
+        .bind(nomination_number)
+        .bind(status)
+        .bind(bid_deadline.map(|d| format!("{}", d)))
+        .execute(&pool)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    // Awards the open lot to its high bidder and moves to the next nomination in one transaction.
+    // The nomination only moves if the lot is still current, so a lot is never awarded twice.
+    // Without a slot the lot closes without a pick. Returns false when the lot was already closed.
+    pub async fn award_auction_lot(
+        auction: &Auction,
+        tournament_position_id: Option<i64>,
+        status: &str,
+        bid_deadline: Option<DateTime<Utc>>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE auction
+            SET
+                status = $3,
+                nomination_number = $2 + 1,
+                bid_deadline = $4::timestamptz,
+                competitor_id = NULL,
+                current_bid = 0,
+                high_bidder_id = NULL
+            WHERE id = $1
+                AND nomination_number = $2
+            ",
+        )
+        .bind(auction.id)
+        .bind(auction.nomination_number)
+        .bind(status)
+        .bind(bid_deadline.map(|d| format!("{}", d)))
+        .execute(&mut *tx)
+        .await?;
+
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        if let Some(tournament_position_id) = tournament_position_id {
+            sqlx::query(
+                "
+                INSERT INTO auction_picks (auction_id, tournament_user_id, tournament_position_id, competitor_id, price, won_at)
+                VALUES ($1, $2, $3, $4, $5, $6::timestamptz)
+                ",
+            )
+            .bind(auction.id)
+            .bind(auction.high_bidder_id)
+            .bind(tournament_position_id)
+            .bind(auction.competitor_id)
+            .bind(auction.current_bid)
+            .bind(format!("{}", chrono::Utc::now()))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    // Puts a competitor up for bid only while `nomination_number` is current and has no lot,
+    // returns false when another request already nominated.
+    pub async fn update_auction_lot(
+        auction_id: i64,
+        nomination_number: i64,
+        competitor_id: i64,
+        tournament_user_id: i64,
+        amount: i64,
+        bid_deadline: DateTime<Utc>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE auction
+            SET competitor_id = $3, high_bidder_id = $4, current_bid = $5, bid_deadline = $6::timestamptz
+            WHERE id = $1
+                AND nomination_number = $2
+                AND competitor_id IS NULL
+            ",
+        )
+        .bind(auction_id)
+        .bind(nomination_number)
+        .bind(competitor_id)
+        .bind(tournament_user_id)
+        .bind(amount)
+        .bind(format!("{}", bid_deadline))
+        .execute(&pool)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    // Only raises the bid when it still beats the current one, so concurrent bids can't undercut each other.
+    pub async fn update_auction_bid(
+        auction_id: i64,
+        tournament_user_id: i64,
+        amount: i64,
+        bid_deadline: DateTime<Utc>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE auction
+            SET high_bidder_id = $2, current_bid = $3, bid_deadline = $4::timestamptz
+            WHERE
+                id = $1
+                AND competitor_id IS NOT NULL
+                AND current_bid < $3
+                AND bid_deadline > $5::timestamptz
+            ",
+        )
+        .bind(auction_id)
+        .bind(tournament_user_id)
+        .bind(amount)
+        .bind(format!("{}", bid_deadline))
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
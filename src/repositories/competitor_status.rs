@@ -0,0 +1,207 @@
+use crate::data::constants::status_consequence;
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::{AffectedPickData, CompetitorStatusChangeData};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::pick_event::PickEventRepository;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct CompetitorStatusRepository;
+
+impl CompetitorStatusRepository {
+    // Applies the status, pick changes, pick events and activity entries in one transaction.
+    // Returns the number of updated competitors, zero when the competitor isn't in the
+    // competition, in which case nothing is written.
+    pub async fn apply_competitor_status(
+        change: &CompetitorStatusChangeData,
+    ) -> Result<u64, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        let updated = sqlx::query(
+            "
+            UPDATE competition_competitor
+            SET is_withdrawn = $3, is_cut = $4, is_suspended = $5, status_consequence = $6
+            WHERE competition_id = $1
+                AND competitor_id = $2
+            ",
+        )
+        .bind(change.competition_id)
+        .bind(change.competitor_id)
+        .bind(change.is_withdrawn)
+        .bind(change.is_cut)
+        .bind(change.is_suspended)
+        .bind(&change.consequence)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            return Ok(0);
+        }
+
+        sqlx::query(
+            "
+            UPDATE tournament_user_picks
+            SET is_invalid = true, last_updated = $2
+            WHERE id = ANY($1)
+            ",
+        )
+        .bind(&change.invalidated_pick_ids)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&mut *tx)
+        .await?;
+
+        for (tournament_user_pick_id, competitor_id) in &change.replacements {
+            sqlx::query(
+                "
+                UPDATE tournament_user_picks
+                SET competitor_id = $2, last_updated = $3
+                WHERE id = $1
+                ",
+            )
+            .bind(tournament_user_pick_id)
+            .bind(competitor_id)
+            .bind(format!("{}", chrono::Utc::now()))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        PickEventRepository::insert_pick_events(&mut tx, &change.events).await?;
+
+        for activity in &change.activities {
+            LeagueRepository::insert_league_activity(&mut tx, activity).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(updated)
+    }
+
+    // Picks in completed workouts or competitions already count and are left alone.
+    pub async fn fetch_affected_picks(
+        competition_id: i64,
+        competitor_id: i64,
+    ) -> Result<Vec<AffectedPickData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.id,
+                t.id AS tournament_id,
+                t.tournament_type_id,
+                tu.id AS tournament_user_id,
+                tu.user_id,
+                tup.tournament_position_id,
+                tup.workout_id,
+                tup.rank,
+                COALESCE(w.is_active, false) AS is_workout_active
+            FROM
+                tournament_user_picks tup
+            JOIN
+                tournament_users tu
+                ON tu.id = tup.tournament_user_id
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            JOIN
+                competition c
+                ON c.id = t.competition_id
+            LEFT JOIN
+                workouts w
+                ON w.id = tup.workout_id
+            WHERE
+                t.competition_id = $1
+                AND tup.competitor_id = $2
+                AND tup.is_invalid = false
+                AND c.is_complete = false
+                AND (w.id IS NULL OR w.is_complete = false)
+            ORDER BY
+                tup.id
+            ",
+        )
+        .bind(competition_id)
+        .bind(competitor_id)
+        .map(|row: PgRow| AffectedPickData {
+            tournament_user_pick_id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            tournament_type_id: row.get("tournament_type_id"),
+            tournament_user_id: row.get("tournament_user_id"),
+            user_id: row.get("user_id"),
+            tournament_position_id: row.get("tournament_position_id"),
+            workout_id: row.get("workout_id"),
+            rank: row.get("rank"),
+            is_workout_active: row.get("is_workout_active"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // A workout limits the lookup to that workout's picks, otherwise the whole lineup is used.
+    pub async fn fetch_member_competitor_ids(
+        tournament_user_id: i64,
+        workout_id: Option<i64>,
+    ) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                competitor_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND is_invalid = false
+                AND ($2::bigint IS NULL OR workout_id = $2)
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_id)
+        .map(|row: PgRow| row.get("competitor_id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // True when the pick's athlete is out of the competition with a free swap granted.
+    pub async fn fetch_is_free_swap(tournament_user_pick_id: i64) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT EXISTS (
+                SELECT
+                    1
+                FROM
+                    tournament_user_picks tup
+                JOIN
+                    tournament_users tu
+                    ON tu.id = tup.tournament_user_id
+                JOIN
+                    tournament t
+                    ON t.id = tu.tournament_id
+                JOIN
+                    competition_competitor cc
+                    ON cc.competition_id = t.competition_id
+                    AND cc.competitor_id = tup.competitor_id
+                WHERE
+                    tup.id = $1
+                    AND cc.status_consequence = $2
+                    AND (cc.is_withdrawn OR cc.is_cut OR cc.is_suspended)
+            ) AS is_free_swap
+            ",
+        )
+        .bind(tournament_user_pick_id)
+        .bind(status_consequence::FREE_SWAP)
+        .map(|row: PgRow| row.get("is_free_swap"))
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
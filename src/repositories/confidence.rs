@@ -0,0 +1,124 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::LeaderboardEntry;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct ConfidenceRepository;
+
+impl ConfidenceRepository {
+    // Top-N placement points (10 - |rank - placement|) multiplied by the pick's confidence weight.
+    pub async fn fetch_confidence_leaderboard(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tu.id as tournament_user_id,
+                au.username,
+                au.profile_url,
+                SUM(
+                    CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0
+                    ELSE (10 - ABS(rank - placement)) * COALESCE(tup.confidence, 0) END
+                )::double precision as points,
+                SUM(CASE WHEN 10 - ABS(rank - placement) = 10 THEN 1 ELSE 0 END) as exact_picks,
+                RANK() OVER (
+                    ORDER BY COALESCE(
+                        SUM(
+                            CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0
+                            ELSE (10 - ABS(rank - placement)) * COALESCE(tup.confidence, 0) END
+                        )::double precision, 0::double precision) DESC,
+                        SUM(CASE WHEN 10 - ABS(rank - placement) = 10 THEN 1 ELSE 0 END) DESC
+                ) AS ordinal
+            FROM tournament_users tu
+                JOIN app_user au
+                    ON au.id = tu.user_id
+                LEFT JOIN tournament_user_picks tup
+                    ON tup.tournament_user_id = tu.id
+                LEFT JOIN competition_leaderboard s
+                    ON s.competitor_id = tup.competitor_id
+                    AND s.competition_id = $2
+            WHERE
+                tu.tournament_id = $1
+                AND (tup.is_invalid IS NULL OR tup.is_invalid = false)
+            GROUP BY
+                tu.id,
+                au.username,
+                au.profile_url
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .map(|row: PgRow| LeaderboardEntry {
+            tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
+            display_name: row.get("username"),
+            avatar: row.get("profile_url"),
+            points: row.try_get("points").unwrap_or(0.0),
+            event_wins: row.get("exact_picks"),
+            ordinal: row.get("ordinal"),
+            events: vec![],
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_pick_count(tournament_user_id: i64) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                t.pick_count
+            FROM
+                tournament_users tu
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            WHERE
+                tu.id = $1
+            ",
+        )
+        .bind(tournament_user_id)
+        .map(|row: PgRow| row.get("pick_count"))
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Returns (tournament_position_id, confidence) for the member's weighted picks of one gender.
+    pub async fn fetch_confidence_weights(
+        tournament_user_id: i64,
+        gender_id: i64,
+    ) -> Result<Vec<(i64, i64)>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.tournament_position_id,
+                tup.confidence
+            FROM
+                tournament_user_picks tup
+            JOIN
+                competitor c
+                ON c.id = tup.competitor_id
+            WHERE
+                tup.tournament_user_id = $1
+                AND c.gender_id = $2
+                AND tup.confidence IS NOT NULL
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(gender_id)
+        .map(|row: PgRow| (row.get("tournament_position_id"), row.get("confidence")))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::data::constants::tournament_type;
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::{ConsensusPickData, MemberPickData};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct ConsensusRepository;
+
+impl ConsensusRepository {
+    // Pick percentage is the share of the competition's Top-N entries that picked the competitor.
+    pub async fn fetch_top_n_consensus(
+        competition_id: i64,
+        gender_id: i64,
+    ) -> Result<Vec<ConsensusPickData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH entries AS (
+                SELECT
+                    COUNT(*) AS total
+                FROM tournament_users tu
+                JOIN tournament t ON t.id = tu.tournament_id
+                WHERE t.competition_id = $1
+                    AND t.tournament_type_id = $3
+            )
+            SELECT
+                tup.competitor_id,
+                COUNT(DISTINCT tup.tournament_user_id)::double precision
+                    / NULLIF(MAX(e.total), 0) * 100 AS pick_percentage,
+                AVG(tup.rank)::double precision AS average_rank
+            FROM
+                tournament_user_picks tup
+            JOIN
+                tournament_users tu
+                ON tu.id = tup.tournament_user_id
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            JOIN
+                competitor c
+                ON c.id = tup.competitor_id
+            CROSS JOIN
+                entries e
+            WHERE
+                t.competition_id = $1
+                AND t.tournament_type_id = $3
+                AND c.gender_id = $2
+                AND tup.is_invalid = false
+            GROUP BY
+                tup.competitor_id
+            ORDER BY
+                pick_percentage DESC,
+                average_rank
+            ",
+        )
+        .bind(competition_id)
+        .bind(gender_id)
+        .bind(tournament_type::TOP_N)
+        .map(|row: PgRow| ConsensusPickData {
+            competitor_id: row.get("competitor_id"),
+            pick_percentage: row.try_get("pick_percentage").unwrap_or(0.0),
+            average_rank: row.get("average_rank"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_member_picks(tournament_id: i64) -> Result<Vec<MemberPickData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.tournament_user_id,
+                tup.competitor_id,
+                tup.workout_id
+            FROM
+                tournament_user_picks tup
+            JOIN
+                tournament_users tu
+                ON tu.id = tup.tournament_user_id
+            WHERE
+                tu.tournament_id = $1
+                AND tup.is_invalid = false
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| MemberPickData {
+            tournament_user_id: row.get("tournament_user_id"),
+            competitor_id: row.get("competitor_id"),
+            workout_id: row.get("workout_id"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::data::constants::draft_status;
+use crate::data::data_client::DataClient;
+use crate::data::models::draft::Draft;
+use crate::handlers::draft::response_models::{DraftOrderEntry, DraftPickResponse};
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct DraftRepository;
+
+impl DraftRepository {
+    fn map_draft(row: PgRow) -> Draft {
+        Draft {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            competition_id: row.get("competition_id"),
+            status: row.get("status"),
+            pick_seconds: row.get("pick_seconds"),
+            rounds: row.get::<Option<i64>, _>("rounds").unwrap_or(0),
+            current_pick: row.get("current_pick"),
+            pick_deadline: row.get("pick_deadline"),
+        }
+    }
+
+    pub async fn fetch_draft(tournament_id: i64) -> Result<Draft, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                d.id,
+                d.tournament_id,
+                t.competition_id,
+                d.status,
+                d.pick_seconds,
+                t.pick_count as rounds,
+                d.current_pick,
+                d.pick_deadline
+            FROM
+                draft d
+            JOIN
+                tournament t
+                ON t.id = d.tournament_id
+            WHERE
+                d.tournament_id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .map(Self::map_draft)
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_expired_drafts() -> Result<Vec<Draft>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                d.id,
+                d.tournament_id,
+                t.competition_id,
+                d.status,
+                d.pick_seconds,
+                t.pick_count as rounds,
+                d.current_pick,
+                d.pick_deadline
+            FROM
+                draft d
+            JOIN
+                tournament t
+                ON t.id = d.tournament_id
+            WHERE
+                d.status = $1
+                AND d.pick_deadline < $2::timestamptz
+            ",
+        )
+        .bind(draft_status::ACTIVE)
+        .bind(format!("{}", chrono::Utc::now()))
+        .map(Self::map_draft)
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_draft_order(draft_id: i64) -> Result<Vec<DraftOrderEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                dor.tournament_user_id,
+                dor.slot,
+                tu.display_name,
+                au.username,
+                au.profile_url
+            FROM
+                draft_order dor
+            JOIN
+                tournament_users tu
+                ON tu.id = dor.tournament_user_id
+            JOIN
+                app_user au
+                ON au.id = tu.user_id
+            WHERE
+                dor.draft_id = $1
+            ORDER BY
+                dor.slot
+            ",
+        )
+        .bind(draft_id)
+        .map(|row: PgRow| DraftOrderEntry {
+            tournament_user_id: row.get("tournament_user_id"),
+            display_name: row
+                .get::<Option<String>, _>("display_name")
+                .unwrap_or(row.get("username")),
+            avatar: row.get("profile_url"),
+            slot: row.get("slot"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_draft_picks(draft_id: i64) -> Result<Vec<DraftPickResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                dp.pick_number,
+                dp.round,
+                dp.tournament_user_id,
+                dp.competitor_id,
+                dp.is_auto_pick,
+                c.first_name,
+                c.last_name,
+                c.gender_id
+            FROM
+                draft_picks dp
+            JOIN
+                competitor c
+                ON c.id = dp.competitor_id
+            WHERE
+                dp.draft_id = $1
+            ORDER BY
+                dp.pick_number
+            ",
+        )
+        .bind(draft_id)
+        .map(|row: PgRow| DraftPickResponse {
+            pick_number: row.get("pick_number"),
+            round: row.get("round"),
+            tournament_user_id: row.get("tournament_user_id"),
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            gender_id: row.get("gender_id"),
+            is_auto_pick: row.get("is_auto_pick"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn insert_draft(tournament_id: i64, pick_seconds: i64) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            INSERT INTO draft (tournament_id, status, pick_seconds, current_pick)
+            VALUES ($1, $2, $3, 0)
+            RETURNING id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(draft_status::PENDING)
+        .bind(pick_seconds)
+        .fetch_one(&pool)
+        .await?;
+
+        let id = res.get("id");
+
+        Ok(id)
+    }
+
+    pub async fn insert_draft_order(draft_id: i64, tournament_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO draft_order (draft_id, tournament_user_id, slot)
+            SELECT
+                $1,
+                tu.id,
+                ROW_NUMBER() OVER (ORDER BY RANDOM())
+            FROM
+                tournament_users tu
+            WHERE
+                tu.tournament_id = $2
+            ",
+        )
+        .bind(draft_id)
+        .bind(tournament_id)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_draft_clock(
+        draft_id: i64,
+        status: &str,
+        pick_seconds: i64,
+        current_pick: i64,
+        pick_deadline: Option<DateTime<Utc>>,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            UPDATE draft
+            SET status = $2, pick_seconds = $3, current_pick = $4, pick_deadline = $5::timestamptz
+            WHERE id = $1
+            ",
+        )
+        .bind(draft_id)
+        .bind(status)
+        .bind(pick_seconds)
+        .bind(current_pick)
+        .bind(pick_deadline.map(|d| format!("{}", d)))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Moves the clock past the draft's current pick and records the pick in one transaction.
+    // The clock only moves if it's still on that pick, returns false when another request
+    // already made it.
+    pub async fn record_draft_pick(
+        draft: &Draft,
+        round: i64,
+        tournament_user_id: i64,
+        competitor_id: i64,
+        is_auto_pick: bool,
+        status: &str,
+        pick_deadline: Option<DateTime<Utc>>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE draft
+            SET status = $3, current_pick = $2 + 1, pick_deadline = $4::timestamptz
+            WHERE id = $1
+                AND current_pick = $2
+            ",
+        )
+        .bind(draft.id)
+        .bind(draft.current_pick)
+        .bind(status)
+        .bind(pick_deadline.map(|d| format!("{}", d)))
+        .execute(&mut *tx)
+        .await?;
+
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "
+            INSERT INTO draft_picks (draft_id, tournament_user_id, competitor_id, pick_number, round, is_auto_pick, picked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7::timestamptz)
+            ",
+        )
+        .bind(draft.id)
+        .bind(tournament_user_id)
+        .bind(competitor_id)
+        .bind(draft.current_pick)
+        .bind(round)
+        .bind(is_auto_pick)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,161 @@
+use crate::data::constants::{captain, tournament_type};
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::GlobalLeaderboardEntry;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct GlobalLeaderboardRepository;
+
+impl GlobalLeaderboardRepository {
+    // Ranks every entry of the competition's Top-N or ShotCaller leagues together, scored the
+    // same way as the per-tournament leaderboards. Rows are returned in leaderboard order after
+    // the given position, optionally narrowed to a single entry.
+    pub async fn fetch_global_leaderboard(
+        competition_id: i64,
+        tournament_type_id: i64,
+        tournament_user_id: Option<i64>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<GlobalLeaderboardEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH pick_points AS (
+                SELECT
+                    tup.tournament_user_id,
+                    CASE
+                        WHEN $2 = $4 THEN GREATEST(10 - ABS(tup.rank - cl.placement), 0)::double precision
+                        ELSE s.points
+                    END * CASE WHEN tup.is_captain THEN $3 ELSE 1 END AS points,
+                    CASE
+                        WHEN $2 = $4 THEN 10 - ABS(tup.rank - cl.placement) = 10
+                        ELSE s.points = 100
+                    END AS is_exact
+                FROM tournament_user_picks tup
+                    JOIN tournament_users tu
+                        ON tu.id = tup.tournament_user_id
+                    JOIN tournament t
+                        ON t.id = tu.tournament_id
+                    LEFT JOIN competition_leaderboard cl
+                        ON cl.competitor_id = tup.competitor_id
+                        AND cl.competition_id = t.competition_id
+                    LEFT JOIN workouts w
+                        ON w.id = tup.workout_id
+                    LEFT JOIN score s
+                        ON s.competitor_id = tup.competitor_id
+                        AND s.ordinal = w.ordinal
+                        AND s.competition_id = t.competition_id
+                WHERE
+                    t.competition_id = $1
+                    AND t.tournament_type_id = $2
+                    AND tup.is_invalid = false
+            ),
+            ranked AS (
+                SELECT
+                    tu.id AS tournament_user_id,
+                    t.id AS tournament_id,
+                    t.name AS tournament,
+                    au.username,
+                    au.profile_url,
+                    COALESCE(SUM(pp.points), 0::double precision) AS points,
+                    COUNT(*) FILTER (WHERE pp.is_exact) AS exact_picks,
+                    RANK() OVER (
+                        ORDER BY COALESCE(SUM(pp.points), 0::double precision) DESC,
+                        COUNT(*) FILTER (WHERE pp.is_exact) DESC
+                    ) AS ordinal,
+                    ROW_NUMBER() OVER (
+                        ORDER BY COALESCE(SUM(pp.points), 0::double precision) DESC,
+                        COUNT(*) FILTER (WHERE pp.is_exact) DESC,
+                        tu.id
+                    ) AS position,
+                    COUNT(*) OVER () AS total
+                FROM tournament_users tu
+                    JOIN tournament t
+                        ON t.id = tu.tournament_id
+                    JOIN app_user au
+                        ON au.id = tu.user_id
+                    LEFT JOIN pick_points pp
+                        ON pp.tournament_user_id = tu.id
+                WHERE
+                    t.competition_id = $1
+                    AND t.tournament_type_id = $2
+                GROUP BY
+                    tu.id,
+                    t.id,
+                    t.name,
+                    au.username,
+                    au.profile_url
+            )
+            SELECT
+                *
+            FROM
+                ranked
+            WHERE
+                ($5::bigint IS NULL OR tournament_user_id = $5)
+                AND position > $6
+            ORDER BY
+                position
+            LIMIT $7
+            ",
+        )
+        .bind(competition_id)
+        .bind(tournament_type_id)
+        .bind(captain::MULTIPLIER)
+        .bind(tournament_type::TOP_N)
+        .bind(tournament_user_id)
+        .bind(offset)
+        .bind(limit)
+        .map(|row: PgRow| {
+            let ordinal: i64 = row.get("ordinal");
+            let total: i64 = row.get("total");
+
+            GlobalLeaderboardEntry {
+                tournament_user_id: row.get("tournament_user_id"),
+                tournament_id: row.get("tournament_id"),
+                tournament: row.get("tournament"),
+                display_name: row.get("username"),
+                avatar: row.get("profile_url"),
+                points: row.get("points"),
+                event_wins: row.get("exact_picks"),
+                ordinal,
+                position: row.get("position"),
+                // Share of the field ranked at or below this entry, so the leader sits at 100.
+                percentile: (total - ordinal + 1) as f64 / total as f64 * 100.0,
+            }
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_global_leaderboard_count(
+        competition_id: i64,
+        tournament_type_id: i64,
+    ) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                COUNT(*) AS total
+            FROM
+                tournament_users tu
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            WHERE
+                t.competition_id = $1
+                AND t.tournament_type_id = $2
+            ",
+        )
+        .bind(competition_id)
+        .bind(tournament_type_id)
+        .map(|row: PgRow| row.get("total"))
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
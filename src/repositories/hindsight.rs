@@ -0,0 +1,54 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::HindsightScoreData;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct HindsightRepository;
+
+impl HindsightRepository {
+    pub async fn fetch_workout_scores(
+        competition_id: i64,
+    ) -> Result<Vec<HindsightScoreData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                s.competitor_id,
+                c.first_name,
+                c.last_name,
+                cc.position_id,
+                s.ordinal,
+                s.points
+            FROM
+                score s
+            JOIN
+                competitor c
+                ON c.id = s.competitor_id
+            JOIN
+                competition_competitor cc
+                ON cc.competitor_id = s.competitor_id
+                AND cc.competition_id = s.competition_id
+            WHERE
+                s.competition_id = $1
+                AND s.points IS NOT NULL
+            ORDER BY
+                s.points DESC,
+                s.competitor_id
+            ",
+        )
+        .bind(competition_id)
+        .map(|row: PgRow| HindsightScoreData {
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            position_id: row.get("position_id"),
+            ordinal: row.get("ordinal"),
+            points: row.get("points"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -1,8 +1,12 @@
+use crate::data::constants::{captain, competition, invitation_status, pick_action};
+use crate::data::models::tournament_invitation::TournamentInvitation;
+use crate::data::models::tournament_waitlist::TournamentWaitlist;
 use crate::data::models::workout::Workout;
 use crate::handlers::league::response_models::{
     CompetitionLeaderboardResponse, LeaderboardEntry, LeaderboardPicks, LeaderboardShotcallerPicks,
-    LeaderboardShotcallerTournamentUserData, LeaguePosition, MatchupShotcallerPick, PickCompetitor,
-    PickPercentage, PropBet, PropBetOption, UserLeaguesTopPicksDataResponse,
+    LeaderboardShotcallerTournamentUserData, LeagueActivityData, LeagueActivityResponse,
+    LeagueInvitationResponse, LeaguePosition, MatchupShotcallerPick, PickCompetitor,
+    PickPercentage, PropBet, PropBetOption, TournamentMemberData, UserLeaguesTopPicksDataResponse,
     WorkoutPredictionCountResponse, WorkoutPredictionResponse, WorkoutResponse,
 };
 use crate::{
@@ -22,14 +26,14 @@ use crate::data::models::workout_stage_movement::WorkoutStageMovement;
 use crate::data::models::workout_stages::WorkoutStages;
 use crate::data::tournament_pick_count::TournamentPickCount;
 use sqlx::postgres::PgRow;
-use sqlx::{Error, Row};
+use sqlx::{Error, Postgres, Row, Transaction};
 use std::collections::HashMap;
 
 pub struct LeagueRepository;
 
 #[derive(sqlx::Type)]
 #[sqlx(transparent, no_pg_array)]
-struct Picks(Vec<(i64, i64, i64)>);
+struct Picks(Vec<(i64, i64, i64, bool)>);
 
 impl LeagueRepository {
     pub async fn fetch_competition(tournament_id: i64) -> Result<LeaderboardMetadataData, Error> {
@@ -249,7 +253,9 @@ impl LeagueRepository {
                 tup.id,
                 tup.competitor_id,
                 tup.rank,
-                tup.tournament_position_id
+                tup.tournament_position_id,
+                tup.confidence,
+                tup.is_captain
             FROM
                 tournament_user_picks tup
             WHERE
@@ -262,6 +268,8 @@ impl LeagueRepository {
             rank: row.get("rank"),
             id: row.get("id"),
             tournament_position_id: row.get("tournament_position_id"),
+            confidence: row.get("confidence"),
+            is_captain: row.get("is_captain"),
         })
         .fetch_one(&pool)
         .await?;
@@ -309,6 +317,40 @@ impl LeagueRepository {
         return Ok(res);
     }
 
+    pub async fn fetch_competition_tournament_status_by_tournament(
+        tournament_id: i64,
+    ) -> Result<UserLeagueTournamentCompetitionStatus, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tournament.tournament_type_id,
+                competition.locked_events,
+                competition.is_active,
+                competition.is_complete
+            FROM
+                competition
+            JOIN
+                tournament
+                ON tournament.competition_id = competition.id
+            WHERE
+                tournament.id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| UserLeagueTournamentCompetitionStatus {
+            is_active: row.get("is_active"),
+            is_complete: row.get("is_complete"),
+            tournament_type_id: row.get("tournament_type_id"),
+            locked_events: row.get("locked_events"),
+        })
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
     pub async fn fetch_shot_caller_pick_id(
         tournament_user_id: i64,
         workout_id: i64,
@@ -795,11 +837,17 @@ impl LeagueRepository {
                 tu.id as tournament_user_id,
                 au.username,
                 au.profile_url,
-                SUM(CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0 ELSE 10 - ABS(rank - placement) END) as points,
+                SUM(
+                    CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0 ELSE 10 - ABS(rank - placement) END
+                    * CASE WHEN tup.is_captain THEN $3 ELSE 1 END
+                ) as points,
                 SUM(CASE WHEN 10 - ABS(rank - placement) = 10 THEN 1 ELSE 0 END) as exact_picks,
                 RANK() OVER (
                     ORDER BY COALESCE(
-                        SUM(CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0 ELSE 10 - ABS(rank - placement) END), 0::double precision) DESC,
+                        SUM(
+                            CASE WHEN 10 - ABS(rank - placement) < 0 THEN 0 ELSE 10 - ABS(rank - placement) END
+                            * CASE WHEN tup.is_captain THEN $3 ELSE 1 END
+                        ), 0::double precision) DESC,
                         SUM(CASE WHEN 10 - ABS(rank - placement) = 10 THEN 1 ELSE 0 END) DESC
                 ) AS ordinal
             FROM tournament_users tu
@@ -821,6 +869,7 @@ impl LeagueRepository {
         )
         .bind(tournament_id)
         .bind(competition_id)
+        .bind(captain::MULTIPLIER)
         .map(|row: sqlx::postgres::PgRow| LeaderboardEntry {
             tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
             display_name: row.get("username"),
@@ -828,6 +877,7 @@ impl LeagueRepository {
             points: row.try_get("points").unwrap_or(0.0),
             event_wins: row.get("exact_picks"),
             ordinal: row.get("ordinal"),
+            events: vec![],
         })
         .fetch_all(&pool)
         .await?;
@@ -847,10 +897,13 @@ impl LeagueRepository {
                 tu.id as tournament_user_id,
                 au.username,
                 au.profile_url,
-                sum(s.points) AS points,
+                sum(s.points * CASE WHEN tup.is_captain THEN $3 ELSE 1 END) AS points,
                 COUNT(*) FILTER (WHERE s.points = 100) AS exact_picks,
                 RANK() OVER (
-                    ORDER BY COALESCE(SUM(s.points), 0::double precision) DESC,
+                    ORDER BY COALESCE(
+                        SUM(s.points * CASE WHEN tup.is_captain THEN $3 ELSE 1 END),
+                        0::double precision
+                    ) DESC,
                     COUNT(*) FILTER (WHERE s.points = 100) DESC
                 ) AS ordinal
             FROM tournament_users tu
@@ -875,6 +928,7 @@ impl LeagueRepository {
         )
         .bind(tournament_id)
         .bind(competition_id)
+        .bind(captain::MULTIPLIER)
         .map(|row: sqlx::postgres::PgRow| LeaderboardEntry {
             tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
             display_name: row.get("username"),
@@ -882,6 +936,7 @@ impl LeagueRepository {
             points: row.try_get("points").unwrap_or(0.0),
             event_wins: row.get("exact_picks"),
             ordinal: row.get("ordinal"),
+            events: vec![],
         })
         .fetch_all(&pool)
         .await?;
@@ -889,6 +944,143 @@ impl LeagueRepository {
         return Ok(res);
     }
 
+    // Top-N points are re-scored against the cumulative standings after each workout, so the
+    // returned (tournament_user_id, ordinal, points) rows are running totals. The latest workout
+    // uses the competition leaderboard placements, the same source as the headline total.
+    // Earlier workouts rank every competitor on cumulative points, counting missing scores as
+    // zero, and ties share a placement as there is no leaderboard history to break them with.
+    pub async fn fetch_top_10_event_points(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<(i64, i64, f64)>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH scored AS (
+                SELECT DISTINCT s.ordinal
+                FROM score s
+                WHERE s.competition_id = $2
+                    AND s.points IS NOT NULL
+            ),
+            cumulative AS (
+                SELECT
+                    cc.competitor_id,
+                    sc.ordinal,
+                    c.gender_id,
+                    SUM(COALESCE(s.points, 0)) OVER (
+                        PARTITION BY cc.competitor_id ORDER BY sc.ordinal
+                    ) AS total
+                FROM competition_competitor cc
+                JOIN competitor c ON c.id = cc.competitor_id
+                CROSS JOIN scored sc
+                LEFT JOIN score s
+                    ON s.competitor_id = cc.competitor_id
+                    AND s.competition_id = cc.competition_id
+                    AND s.ordinal = sc.ordinal
+                    AND s.points IS NOT NULL
+                WHERE cc.competition_id = $2
+            ),
+            placements AS (
+                SELECT
+                    competitor_id,
+                    ordinal,
+                    RANK() OVER (PARTITION BY ordinal, gender_id ORDER BY total DESC) AS placement
+                FROM cumulative
+                WHERE ordinal < (SELECT MAX(ordinal) FROM scored)
+                UNION ALL
+                SELECT
+                    cl.competitor_id,
+                    (SELECT MAX(ordinal) FROM scored) AS ordinal,
+                    cl.placement
+                FROM competition_leaderboard cl
+                WHERE cl.competition_id = $2
+                    AND cl.placement IS NOT NULL
+                    AND EXISTS (SELECT 1 FROM scored)
+            )
+            SELECT
+                tup.tournament_user_id,
+                p.ordinal,
+                SUM(
+                    GREATEST(10 - ABS(tup.rank - p.placement), 0)
+                    * CASE WHEN tup.is_captain THEN $3 ELSE 1 END
+                )::double precision AS points
+            FROM tournament_user_picks tup
+                JOIN tournament_users tu
+                    ON tu.id = tup.tournament_user_id
+                JOIN placements p
+                    ON p.competitor_id = tup.competitor_id
+            WHERE
+                tu.tournament_id = $1
+                AND tup.is_invalid = false
+            GROUP BY
+                tup.tournament_user_id,
+                p.ordinal
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .bind(captain::MULTIPLIER)
+        .map(|row: PgRow| {
+            (
+                row.get("tournament_user_id"),
+                row.get("ordinal"),
+                row.get("points"),
+            )
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Returns the (tournament_user_id, ordinal, points) earned in each workout.
+    pub async fn fetch_shotcaller_event_points(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<(i64, i64, f64)>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.tournament_user_id,
+                w.ordinal,
+                SUM(s.points * CASE WHEN tup.is_captain THEN $3 ELSE 1 END) AS points
+            FROM tournament_user_picks tup
+                JOIN tournament_users tu
+                    ON tu.id = tup.tournament_user_id
+                JOIN workouts w
+                    ON w.id = tup.workout_id
+                JOIN score s
+                    ON s.competitor_id = tup.competitor_id
+                    AND s.ordinal = w.ordinal
+                    AND s.competition_id = $2
+            WHERE
+                tu.tournament_id = $1
+                AND tup.is_invalid = false
+                AND s.points IS NOT NULL
+            GROUP BY
+                tup.tournament_user_id,
+                w.ordinal
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .bind(captain::MULTIPLIER)
+        .map(|row: PgRow| {
+            (
+                row.get("tournament_user_id"),
+                row.get("ordinal"),
+                row.get("points"),
+            )
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
     pub async fn fetch_competition_leaderboard(
         competition_id: i64,
         gender_id: i64,
@@ -903,6 +1095,8 @@ impl LeagueRepository {
                 competitor.first_name,
                 competitor.last_name,
                 competition_competitor.is_withdrawn,
+                competition_competitor.is_cut,
+                competition_competitor.is_suspended,
                 COALESCE(points, 0) as points,
                 ordinal_finishes,
                 COALESCE(placement, 0) as placement
@@ -933,6 +1127,8 @@ impl LeagueRepository {
                 finishes: row.try_get("ordinal_finishes").unwrap_or(vec![]),
                 placement: row.get("placement"),
                 is_withdrawn: row.get("is_withdrawn"),
+                is_cut: row.get("is_cut"),
+                is_suspended: row.get("is_suspended"),
             },
         )
         .fetch_all(&pool)
@@ -947,6 +1143,13 @@ impl LeagueRepository {
         tournament_id: i64,
         user_id: i64,
         competitor_id: i64,
+    ) -> Result<Vec<LeaderboardTournamentUserData>, Error> {
+        Self::fetch_matchup_members(tournament_id, &[user_id, competitor_id]).await
+    }
+
+    pub async fn fetch_matchup_members(
+        tournament_id: i64,
+        tournament_user_ids: &[i64],
     ) -> Result<Vec<LeaderboardTournamentUserData>, Error> {
         let pool = DataClient::connect().await?;
 
@@ -957,7 +1160,7 @@ impl LeagueRepository {
                 tu.display_name,
                 au.profile_url,
                 au.username,
-                ARRAY_AGG((tup.competitor_id, tup.rank, c.gender_id)) as picks
+                ARRAY_AGG((tup.competitor_id, tup.rank, c.gender_id, COALESCE(tup.is_captain, false))) as picks
             FROM
                 tournament_users as tu
             JOIN
@@ -966,13 +1169,13 @@ impl LeagueRepository {
             LEFT JOIN
                 tournament_user_picks as tup
                 ON tup.tournament_user_id = tu.id
+                AND tup.is_invalid = false
             LEFT JOIN
                 competitor as c
                 ON c.id = tup.competitor_id
             WHERE
                 tu.tournament_id = $1
-                AND (tu.id = $2 OR tu.id = $3)
-                AND tup.is_invalid = false
+                AND tu.id = ANY($2)
             GROUP BY
                 tu.id,
                 tu.display_name,
@@ -981,20 +1184,22 @@ impl LeagueRepository {
             ",
         )
         .bind(tournament_id)
-        .bind(user_id)
-        .bind(competitor_id)
+        .bind(tournament_user_ids)
         .map(|row: sqlx::postgres::PgRow| {
+            let display_name = row.get::<Option<String>, _>("display_name");
             let picks = row.try_get::<Picks, _>("picks").unwrap_or(Picks(vec![])).0;
+
             LeaderboardTournamentUserData {
                 tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
-                display_name: "".to_string(),
-                avatar: "".to_string(),
+                display_name: display_name.unwrap_or_else(|| row.get("username")),
+                avatar: row.get("profile_url"),
                 men_competitor_ids: picks
                     .iter()
                     .filter(|p| p.2 == 1)
                     .map(|p| LeaderboardPicks {
                         competitor_id: p.0,
                         rank: p.1,
+                        is_captain: p.3,
                     })
                     .collect::<Vec<LeaderboardPicks>>(),
                 women_competitor_ids: picks
@@ -1003,6 +1208,7 @@ impl LeagueRepository {
                     .map(|p| LeaderboardPicks {
                         competitor_id: p.0,
                         rank: p.1,
+                        is_captain: p.3,
                     })
                     .collect::<Vec<LeaderboardPicks>>(),
             }
@@ -1010,21 +1216,27 @@ impl LeagueRepository {
         .fetch_all(&pool)
         .await?;
 
-        return Ok(res);
+        Ok(res)
     }
 
+    // Loads the picks of several members at once, keyed by tournament user. Picks for workouts
+    // that haven't started are left out unless `include_unlocked` is set, which is only for
+    // server-side use and never for picks returned to another member.
     pub async fn fetch_shotcaller_picks(
         tournament_id: i64,
-        user_id: i64,
-    ) -> Result<Vec<MatchupShotcallerPick>, Error> {
+        tournament_user_ids: &[i64],
+        include_unlocked: bool,
+    ) -> Result<HashMap<i64, Vec<MatchupShotcallerPick>>, Error> {
         let pool = DataClient::connect().await?;
 
         let res = sqlx::query(
             "
             SELECT
+                tu.id as tournament_user_id,
                 tup.competitor_id,
                 tup.tournament_position_id,
                 tup.workout_id,
+                tup.is_captain,
                 c.first_name,
                 c.last_name,
                 cc.is_suspended,
@@ -1053,27 +1265,47 @@ impl LeagueRepository {
                 ON s.competitor_id = c.id AND s.competition_id = t.competition_id AND s.ordinal = w.ordinal
             WHERE
                 tu.tournament_id = $1
-                AND tu.id = $2
+                AND tu.id = ANY($2)
                 AND tup.is_invalid = false
+                AND ($3 OR w.is_active = true OR w.is_complete = true)
             ",
         )
         .bind(tournament_id)
-        .bind(user_id)
-        .map(|row: PgRow| MatchupShotcallerPick {
-            competitor_id: row.get("competitor_id"),
-            tournament_position_id: row.get("tournament_position_id"),
-            workout_id: row.get("workout_id"),
-            first_name: row.get("first_name"),
-            last_name: row.get("last_name"),
-            is_suspended: row.get("is_suspended"),
-            is_cut: row.get("is_cut"),
-            is_withdrawn: row.get("is_withdrawn"),
-            points: row.try_get("points").unwrap_or(0.0),
+        .bind(tournament_user_ids)
+        .bind(include_unlocked)
+        .map(|row: PgRow| {
+            let is_captain: bool = row.get("is_captain");
+            let points: f64 = row.try_get("points").unwrap_or(0.0);
+
+            let pick = MatchupShotcallerPick {
+                competitor_id: row.get("competitor_id"),
+                tournament_position_id: row.get("tournament_position_id"),
+                workout_id: row.get("workout_id"),
+                first_name: row.get("first_name"),
+                last_name: row.get("last_name"),
+                is_suspended: row.get("is_suspended"),
+                is_cut: row.get("is_cut"),
+                is_withdrawn: row.get("is_withdrawn"),
+                points: if is_captain {
+                    points * captain::MULTIPLIER
+                } else {
+                    points
+                },
+                is_captain,
+            };
+
+            (row.get::<i64, _>("tournament_user_id"), pick)
         })
         .fetch_all(&pool)
         .await?;
 
-        return Ok(res);
+        let mut result: HashMap<i64, Vec<MatchupShotcallerPick>> = HashMap::new();
+
+        for (tournament_user_id, pick) in res {
+            result.entry(tournament_user_id).or_default().push(pick);
+        }
+
+        Ok(result)
     }
 
     pub async fn fetch_tournament_users(
@@ -1088,7 +1320,7 @@ impl LeagueRepository {
                 tu.display_name,
                 au.profile_url,
                 au.username,
-                ARRAY_AGG((tup.competitor_id, tup.rank, c.gender_id)) as picks
+                ARRAY_AGG((tup.competitor_id, tup.rank, c.gender_id, COALESCE(tup.is_captain, false))) as picks
             FROM
                 tournament_users as tu
             JOIN
@@ -1128,6 +1360,7 @@ impl LeagueRepository {
                     .map(|p| LeaderboardPicks {
                         competitor_id: p.0,
                         rank: p.1,
+                        is_captain: p.3,
                     })
                     .collect::<Vec<LeaderboardPicks>>(),
                 women_competitor_ids: picks
@@ -1136,6 +1369,7 @@ impl LeagueRepository {
                     .map(|p| LeaderboardPicks {
                         competitor_id: p.0,
                         rank: p.1,
+                        is_captain: p.3,
                     })
                     .collect::<Vec<LeaderboardPicks>>(),
             }
@@ -1352,6 +1586,7 @@ impl LeagueRepository {
                 competition.is_complete,
                 tournament.tournament_type_id,
                 tournament.pick_count,
+                tournament.max_entries,
                 tournament_positions.id as position_id,
                 positions.name as position_name,
                 positions.abbreviation as position_abbreviation,
@@ -1374,10 +1609,11 @@ impl LeagueRepository {
                 ON tournament_positions.position_id = positions.id
             WHERE
                 tournament_users.user_id = $1
-                AND competition.id >= 28
+                AND competition.id >= $2
             ",
         )
         .bind(user_league.user_id)
+        .bind(competition::FIRST_LEAGUE_COMPETITION_ID)
         .map(|row: PgRow| {
             let tu: i64 = row.get::<i64, _>("tournament_users_id");
             let existing_user_league = base_user_leagues.get(&tu);
@@ -1402,6 +1638,9 @@ impl LeagueRepository {
                         tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
                         pick_count: row.get::<Option<i64>, _>("pick_count"),
                         positions: vec![],
+                        invitation_id: None,
+                        max_entries: row.get("max_entries"),
+                        waitlist_position: None,
                     },
                 );
             }
@@ -1445,7 +1684,8 @@ impl LeagueRepository {
                 tournament_user_picks.id,
                 tournament_user_picks.competitor_id,
                 tournament_user_picks.workout_id,
-                tournament_user_picks.tournament_position_id
+                tournament_user_picks.tournament_position_id,
+                tournament_user_picks.is_captain
             FROM
                 tournament_user_picks
             JOIN
@@ -1462,6 +1702,7 @@ impl LeagueRepository {
             competitor_id: row.get::<i64, _>("competitor_id") as u64,
             workout_id: row.get::<Option<i64>, _>("workout_id"),
             tournament_position_id: row.get::<i64, _>("tournament_position_id") as u64,
+            is_captain: row.get("is_captain"),
         })
         .fetch_all(&pool)
         .await?;
@@ -1481,7 +1722,9 @@ impl LeagueRepository {
                 tournament_user_picks.competitor_id,
                 tournament_user_picks.rank,
                 competitor.gender_id,
-                tournament_user_picks.tournament_position_id
+                tournament_user_picks.tournament_position_id,
+                tournament_user_picks.confidence,
+                tournament_user_picks.is_captain
             FROM
                 tournament_user_picks
             JOIN
@@ -1499,6 +1742,8 @@ impl LeagueRepository {
             rank: row.get("rank"),
             gender_id: row.get("gender_id"),
             tournament_position_id: row.get::<i64, _>("tournament_position_id") as u64,
+            confidence: row.get("confidence"),
+            is_captain: row.get("is_captain"),
         })
         .fetch_all(&pool)
         .await?;
@@ -1530,140 +1775,789 @@ impl LeagueRepository {
         return Ok(is_user_in_league);
     }
 
-    pub async fn fetch_open_leagues(
-        competition_id: &u64,
-        user_id: &u64,
-    ) -> Result<Vec<OpenLeagueResponse>, Error> {
+    pub async fn fetch_tournament_commissioner_id(tournament_id: i64) -> Result<i64, Error> {
         let pool = DataClient::connect().await?;
 
         let res = sqlx::query(
             "
             SELECT
-                tournament.id,
-                competition_id,
-                name,
-                tournament_type_id,
-                is_private,
-                passcode,
-                tournament.logo,
-                tournament.pick_count,
-                (SELECT COUNT(*) FROM tournament_users WHERE tournament_users.tournament_id = tournament.id) as Entries
+                commissioner_id
             FROM
                 tournament
-            LEFT JOIN tournament_users
-                ON tournament_users.tournament_id = tournament.id
-                AND tournament_users.user_id = $2
             WHERE
-                tournament.competition_id = $1
-                AND tournament_users IS NULL
+                id = $1
             ",
         )
-            .bind(*competition_id as i64)
-            .bind(*user_id as i64)
-            .map(|row: sqlx::postgres::PgRow| OpenLeagueResponse {
-                id: row.get::<i64, _>("id") as u64,
-                competition_id: row.get::<i64, _>("competition_id") as u64,
-                name: row.get("name"),
-                logo: row.get("logo"),
-                tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
-                is_private: row.get("is_private"),
-                passcode: row.get("passcode"),
-                entries: row.get::<i64, _>("entries") as u64,
-                pick_count: row.get::<i64, _>("pick_count") as u64,
-            })
-            .fetch_all(&pool)
-            .await?;
+        .bind(tournament_id)
+        .map(|row: PgRow| row.get("commissioner_id"))
+        .fetch_one(&pool)
+        .await?;
 
-        return Ok(res);
+        Ok(res)
     }
 
-    pub async fn insert_score(
-        competition_id: i64,
-        competitor_id: i64,
-        ordinal: i64,
-        points: f64,
-    ) -> Result<(), Error> {
+    pub async fn fetch_tournament(tournament_id: i64) -> Result<Tournament, Error> {
         let pool = DataClient::connect().await?;
 
-        sqlx::query(
+        let res = sqlx::query(
             "
-            INSERT INTO score (competition_id, competitor_id, ordinal, rank, points)
-            VALUES ($1, $2, $3, 0, $4)
+            SELECT
+                id,
+                competition_id,
+                name,
+                logo,
+                tournament_type_id,
+                is_private,
+                passcode,
+                commissioner_id,
+                pick_count,
+                max_entries
+            FROM
+                tournament
+            WHERE
+                id = $1
             ",
         )
-        .bind(competition_id)
-        .bind(competitor_id)
-        .bind(ordinal)
-        .bind(points)
-        .execute(&pool)
+        .bind(tournament_id)
+        .map(|row: PgRow| Tournament {
+            id: row.get::<i64, _>("id") as u64,
+            competition_id: row.get::<i64, _>("competition_id") as u64,
+            name: row.get("name"),
+            logo: row.get("logo"),
+            tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
+            is_private: row.get("is_private"),
+            passcode: row.get("passcode"),
+            commissioner_id: row.get::<i64, _>("commissioner_id") as u64,
+            entries: None,
+            pick_count: row.get("pick_count"),
+            max_entries: row.get("max_entries"),
+            competition: None,
+            tournament_type: None,
+        })
+        .fetch_one(&pool)
         .await?;
 
-        return Ok(());
+        Ok(res)
     }
 
-    pub async fn insert_tournament_user(tournament_id: i64, user_id: i64) -> Result<i64, Error> {
+    pub async fn fetch_tournament_member(
+        tournament_user_id: i64,
+    ) -> Result<TournamentMemberData, Error> {
         let pool = DataClient::connect().await?;
 
         let res = sqlx::query(
             "
-            INSERT INTO tournament_users (tournament_id, user_id)
-            VALUES ($1, $2)
-            RETURNING id
+            SELECT
+                id,
+                tournament_id,
+                user_id
+            FROM
+                tournament_users
+            WHERE
+                id = $1
             ",
         )
-        .bind(tournament_id)
-        .bind(user_id)
+        .bind(tournament_user_id)
+        .map(|row: PgRow| TournamentMemberData {
+            tournament_user_id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            user_id: row.get("user_id"),
+        })
         .fetch_one(&pool)
         .await?;
 
-        let id = res.get("id");
-
-        return Ok(id);
+        Ok(res)
     }
 
-    pub async fn insert_tournament_position(
+    pub async fn fetch_league_activity(
         tournament_id: i64,
-        position_id: i64,
-        ordinal: i64,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<LeagueActivityResponse>, Error> {
         let pool = DataClient::connect().await?;
 
-        sqlx::query(
+        let res = sqlx::query(
             "
-            INSERT INTO tournament_positions(tournament_id, position_id, ordinal, allowed_positions)
-            VALUES ($1, $2, $3, $4);
+            SELECT
+                ta.id,
+                ta.user_id,
+                au.username,
+                ta.action,
+                ta.detail,
+                ta.created_at
+            FROM
+                tournament_activity ta
+            JOIN
+                app_user au
+                ON au.id = ta.user_id
+            WHERE
+                ta.tournament_id = $1
+            ORDER BY
+                ta.id DESC
             ",
         )
         .bind(tournament_id)
-        .bind(position_id)
-        .bind(ordinal)
-        .bind(if position_id < 5 {
-            Some(vec![position_id])
-        } else if position_id == 5 {
-            Some(vec![1, 2, 3, 4])
-        } else {
-            None
+        .map(|row: PgRow| LeagueActivityResponse {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            username: row.get("username"),
+            action: row.get("action"),
+            detail: row.get("detail"),
+            created_at: row.get("created_at"),
         })
-        .execute(&pool)
+        .fetch_all(&pool)
         .await?;
 
-        return Ok(());
-    }
-
-    pub async fn refresh_competition_leaderboard() -> Result<(), Error> {
-        let pool = DataClient::connect().await?;
-
-        sqlx::query("REFRESH MATERIALIZED VIEW competition_leaderboard")
-            .execute(&pool)
-            .await?;
-
-        return Ok(());
+        Ok(res)
     }
 
-    pub async fn delete_user_league_pick(tournament_user_pick_id: i64) -> Result<(), Error> {
+    pub async fn fetch_tournament_user_count(tournament_id: i64) -> Result<i64, Error> {
         let pool = DataClient::connect().await?;
 
-        let _ = sqlx::query(
+        let res = sqlx::query(
+            "
+            SELECT
+                COUNT(*) as count
+            FROM
+                tournament_users
+            WHERE
+                tournament_id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| row.get("count"))
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waitlist_id(join_league: &JoinLeague) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament_waitlist
+            WHERE
+                tournament_id = $1
+                AND user_id = $2
+            ",
+        )
+        .bind(join_league.tournament_id)
+        .bind(join_league.user_id)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_optional(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waitlist_leagues(user_id: i64) -> Result<Vec<UserLeaguesResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                competition.name as competition_name,
+                competition.id as competition_id,
+                tournament.name as tournament_name,
+                tournament.id as tournament_id,
+                tournament.commissioner_id,
+                tournament.logo,
+                competition.logo as competition_logo,
+                competition.locked_events,
+                competition.is_active,
+                competition.is_complete,
+                tournament.tournament_type_id,
+                tournament.pick_count,
+                tournament.max_entries,
+                waitlist.position
+            FROM
+                (
+                    SELECT
+                        tournament_id,
+                        user_id,
+                        ROW_NUMBER() OVER (PARTITION BY tournament_id ORDER BY id) as position
+                    FROM
+                        tournament_waitlist
+                ) waitlist
+            JOIN
+                tournament
+                ON tournament.id = waitlist.tournament_id
+            JOIN
+                competition
+                ON competition.id = tournament.competition_id
+            WHERE
+                waitlist.user_id = $1
+                AND competition.id >= $2
+            ",
+        )
+        .bind(user_id)
+        .bind(competition::FIRST_LEAGUE_COMPETITION_ID)
+        .map(|row: PgRow| UserLeaguesResponse {
+            tournament_user_id: 0,
+            display_name: None,
+            competition: row.get("competition_name"),
+            competition_id: row.get::<i64, _>("competition_id") as u64,
+            tournament: row.get("tournament_name"),
+            tournament_id: row.get::<i64, _>("tournament_id") as u64,
+            commissioner_id: row.get("commissioner_id"),
+            logo: row
+                .get::<Option<String>, _>("logo")
+                .unwrap_or(row.get("competition_logo")),
+            is_active: row.get("is_active"),
+            is_complete: row.get("is_complete"),
+            locked_events: row.get::<i64, _>("locked_events") as u64,
+            tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
+            pick_count: row.get::<Option<i64>, _>("pick_count"),
+            positions: vec![],
+            invitation_id: None,
+            max_entries: row.get("max_entries"),
+            waitlist_position: Some(row.get("position")),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_pending_invitation_id(
+        tournament_id: i64,
+        invitee_id: i64,
+    ) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament_invitations
+            WHERE
+                tournament_id = $1
+                AND invitee_id = $2
+                AND status = $3
+            ",
+        )
+        .bind(tournament_id)
+        .bind(invitee_id)
+        .bind(invitation_status::PENDING)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_optional(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_invitation(invitation_id: i64) -> Result<TournamentInvitation, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                tournament_id,
+                inviter_id,
+                invitee_id,
+                status,
+                created_at
+            FROM
+                tournament_invitations
+            WHERE
+                id = $1
+            ",
+        )
+        .bind(invitation_id)
+        .map(|row: PgRow| TournamentInvitation {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            inviter_id: row.get("inviter_id"),
+            invitee_id: row.get("invitee_id"),
+            status: row.get("status"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_user_invitations(
+        user_id: i64,
+    ) -> Result<Vec<LeagueInvitationResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                ti.id,
+                ti.tournament_id,
+                ti.created_at,
+                t.name as tournament_name,
+                t.logo,
+                t.tournament_type_id,
+                c.id as competition_id,
+                c.name as competition_name,
+                c.logo as competition_logo,
+                au.username as inviter_username
+            FROM
+                tournament_invitations ti
+            JOIN
+                tournament t
+                ON t.id = ti.tournament_id
+            JOIN
+                competition c
+                ON c.id = t.competition_id
+            JOIN
+                app_user au
+                ON au.id = ti.inviter_id
+            WHERE
+                ti.invitee_id = $1
+                AND ti.status = $2
+            ORDER BY
+                ti.id DESC
+            ",
+        )
+        .bind(user_id)
+        .bind(invitation_status::PENDING)
+        .map(|row: PgRow| LeagueInvitationResponse {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            tournament: row.get("tournament_name"),
+            competition_id: row.get("competition_id"),
+            competition: row.get("competition_name"),
+            logo: row
+                .get::<Option<String>, _>("logo")
+                .unwrap_or(row.get("competition_logo")),
+            tournament_type_id: row.get("tournament_type_id"),
+            invited_by: row.get("inviter_username"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_invitation_leagues(user_id: i64) -> Result<Vec<UserLeaguesResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                ti.id as invitation_id,
+                competition.name as competition_name,
+                competition.id as competition_id,
+                tournament.name as tournament_name,
+                tournament.id as tournament_id,
+                tournament.commissioner_id,
+                tournament.logo,
+                competition.logo as competition_logo,
+                competition.locked_events,
+                competition.is_active,
+                competition.is_complete,
+                tournament.tournament_type_id,
+                tournament.pick_count,
+                tournament.max_entries
+            FROM
+                tournament_invitations ti
+            JOIN
+                tournament
+                ON tournament.id = ti.tournament_id
+            JOIN
+                competition
+                ON competition.id = tournament.competition_id
+            WHERE
+                ti.invitee_id = $1
+                AND ti.status = $2
+                AND competition.id >= $3
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        tournament_waitlist tw
+                    WHERE
+                        tw.tournament_id = ti.tournament_id
+                        AND tw.user_id = ti.invitee_id
+                )
+            ",
+        )
+        .bind(user_id)
+        .bind(invitation_status::PENDING)
+        .bind(competition::FIRST_LEAGUE_COMPETITION_ID)
+        .map(|row: PgRow| UserLeaguesResponse {
+            tournament_user_id: 0,
+            display_name: None,
+            competition: row.get("competition_name"),
+            competition_id: row.get::<i64, _>("competition_id") as u64,
+            tournament: row.get("tournament_name"),
+            tournament_id: row.get::<i64, _>("tournament_id") as u64,
+            commissioner_id: row.get("commissioner_id"),
+            logo: row
+                .get::<Option<String>, _>("logo")
+                .unwrap_or(row.get("competition_logo")),
+            is_active: row.get("is_active"),
+            is_complete: row.get("is_complete"),
+            locked_events: row.get::<i64, _>("locked_events") as u64,
+            tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
+            pick_count: row.get::<Option<i64>, _>("pick_count"),
+            positions: vec![],
+            invitation_id: Some(row.get("invitation_id")),
+            max_entries: row.get("max_entries"),
+            waitlist_position: None,
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_open_leagues(
+        competition_id: &u64,
+        user_id: &u64,
+    ) -> Result<Vec<OpenLeagueResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tournament.id,
+                competition_id,
+                name,
+                tournament_type_id,
+                is_private,
+                passcode,
+                tournament.logo,
+                tournament.pick_count,
+                tournament.max_entries,
+                (SELECT COUNT(*) FROM tournament_users WHERE tournament_users.tournament_id = tournament.id) as Entries
+            FROM
+                tournament
+            LEFT JOIN tournament_users
+                ON tournament_users.tournament_id = tournament.id
+                AND tournament_users.user_id = $2
+            WHERE
+                tournament.competition_id = $1
+                AND tournament_users IS NULL
+            ",
+        )
+            .bind(*competition_id as i64)
+            .bind(*user_id as i64)
+            .map(|row: sqlx::postgres::PgRow| {
+                let entries = row.get::<i64, _>("entries");
+                let max_entries = row.get::<Option<i64>, _>("max_entries");
+
+                OpenLeagueResponse {
+                    id: row.get::<i64, _>("id") as u64,
+                    competition_id: row.get::<i64, _>("competition_id") as u64,
+                    name: row.get("name"),
+                    logo: row.get("logo"),
+                    tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
+                    is_private: row.get("is_private"),
+                    passcode: row.get("passcode"),
+                    entries: entries as u64,
+                    pick_count: row.get::<i64, _>("pick_count") as u64,
+                    max_entries,
+                    remaining_spots: max_entries.map(|m| (m - entries).max(0)),
+                }
+            })
+            .fetch_all(&pool)
+            .await?;
+
+        return Ok(res);
+    }
+
+    pub async fn insert_score(
+        competition_id: i64,
+        competitor_id: i64,
+        ordinal: i64,
+        points: f64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO score (competition_id, competitor_id, ordinal, rank, points)
+            VALUES ($1, $2, $3, 0, $4)
+            ",
+        )
+        .bind(competition_id)
+        .bind(competitor_id)
+        .bind(ordinal)
+        .bind(points)
+        .execute(&pool)
+        .await?;
+
+        return Ok(());
+    }
+
+    pub async fn insert_tournament_user(tournament_id: i64, user_id: i64) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            INSERT INTO tournament_users (tournament_id, user_id)
+            VALUES ($1, $2)
+            RETURNING id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await?;
+
+        let id = res.get("id");
+
+        return Ok(id);
+    }
+
+    // The tournament row is locked so concurrent joins can't both take the last spot.
+    // Returns None when the league is already full.
+    pub async fn insert_tournament_user_with_capacity(
+        tournament_id: i64,
+        user_id: i64,
+    ) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        if !Self::lock_tournament_capacity(&mut tx, tournament_id).await? {
+            return Ok(None);
+        }
+
+        let res = sqlx::query(
+            "
+            INSERT INTO tournament_users (tournament_id, user_id)
+            VALUES ($1, $2)
+            RETURNING id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(user_id)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(res))
+    }
+
+    // Moves the first waitlisted user into the league under the same lock as joins. Returns
+    // the promoted user, None when the league is full or the waitlist is empty.
+    pub async fn promote_next_waitlist_entry(tournament_id: i64) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        if !Self::lock_tournament_capacity(&mut tx, tournament_id).await? {
+            return Ok(None);
+        }
+
+        let next_entry = sqlx::query(
+            "
+            SELECT
+                id,
+                tournament_id,
+                user_id,
+                created_at
+            FROM
+                tournament_waitlist
+            WHERE
+                tournament_id = $1
+            ORDER BY
+                id
+            LIMIT 1
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| TournamentWaitlist {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            user_id: row.get("user_id"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(next_entry) = next_entry else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "
+            INSERT INTO tournament_users (tournament_id, user_id)
+            VALUES ($1, $2)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(next_entry.user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tournament_waitlist
+            WHERE id = $1
+            ",
+        )
+        .bind(next_entry.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(next_entry.user_id))
+    }
+
+    // Locks the tournament row for the rest of the transaction and returns whether there's
+    // room for another member.
+    async fn lock_tournament_capacity(
+        tx: &mut Transaction<'_, Postgres>,
+        tournament_id: i64,
+    ) -> Result<bool, Error> {
+        let max_entries: Option<i64> = sqlx::query(
+            "
+            SELECT
+                max_entries
+            FROM
+                tournament
+            WHERE
+                id = $1
+            FOR UPDATE
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| row.get("max_entries"))
+        .fetch_one(&mut **tx)
+        .await?;
+
+        let entries: i64 = sqlx::query(
+            "
+            SELECT
+                COUNT(*) as count
+            FROM
+                tournament_users
+            WHERE
+                tournament_id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| row.get("count"))
+        .fetch_one(&mut **tx)
+        .await?;
+
+        Ok(entries < max_entries.unwrap_or(i64::MAX))
+    }
+
+    pub async fn insert_invitation(
+        tournament_id: i64,
+        inviter_id: i64,
+        invitee_id: i64,
+    ) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            INSERT INTO tournament_invitations (tournament_id, inviter_id, invitee_id, status, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(inviter_id)
+        .bind(invitee_id)
+        .bind(invitation_status::PENDING)
+        .bind(format!("{}", chrono::Utc::now()))
+        .fetch_one(&pool)
+        .await?;
+
+        let id = res.get("id");
+
+        Ok(id)
+    }
+
+    pub async fn insert_waitlist_entry(tournament_id: i64, user_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO tournament_waitlist (tournament_id, user_id, created_at)
+            VALUES ($1, $2, $3)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(user_id)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_league_activity(
+        tx: &mut Transaction<'_, Postgres>,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "
+            INSERT INTO tournament_activity (tournament_id, user_id, action, detail, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ",
+        )
+        .bind(activity.tournament_id)
+        .bind(activity.user_id)
+        .bind(&activity.action)
+        .bind(&activity.detail)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_tournament_position(
+        tournament_id: i64,
+        position_id: i64,
+        ordinal: i64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO tournament_positions(tournament_id, position_id, ordinal, allowed_positions)
+            VALUES ($1, $2, $3, $4);
+            ",
+        )
+        .bind(tournament_id)
+        .bind(position_id)
+        .bind(ordinal)
+        .bind(if position_id < 5 {
+            Some(vec![position_id])
+        } else if position_id == 5 {
+            Some(vec![1, 2, 3, 4])
+        } else {
+            None
+        })
+        .execute(&pool)
+        .await?;
+
+        return Ok(());
+    }
+
+    pub async fn refresh_competition_leaderboard() -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query("REFRESH MATERIALIZED VIEW competition_leaderboard")
+            .execute(&pool)
+            .await?;
+
+        return Ok(());
+    }
+
+    pub async fn delete_user_league_pick(tournament_user_pick_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        let _ = sqlx::query(
             "
             DELETE FROM tournament_user_picks
             WHERE id = $1
@@ -1676,131 +2570,337 @@ impl LeagueRepository {
         return Ok(());
     }
 
+    // Removes the league and every row that belongs to it in one transaction, children before
+    // the members, positions and tournament they reference.
     pub async fn delete_tournament(tournament_id: i64) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
 
-        let _ = sqlx::query(
+        let statements = [
+            "
+            DELETE FROM tournament_user_picks
+            USING tournament_users
+            WHERE
+                tournament_users.id = tournament_user_picks.tournament_user_id
+                AND tournament_users.tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_user_pick_events
+            USING tournament_users
+            WHERE
+                tournament_users.id = tournament_user_pick_events.tournament_user_id
+                AND tournament_users.tournament_id = $1
+            ",
+            "
+            DELETE FROM draft_picks
+            USING draft
+            WHERE
+                draft.id = draft_picks.draft_id
+                AND draft.tournament_id = $1
+            ",
+            "
+            DELETE FROM draft_order
+            USING draft
+            WHERE
+                draft.id = draft_order.draft_id
+                AND draft.tournament_id = $1
+            ",
+            "
+            DELETE FROM draft
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM auction_picks
+            USING auction
+            WHERE
+                auction.id = auction_picks.auction_id
+                AND auction.tournament_id = $1
+            ",
+            "
+            DELETE FROM auction_order
+            USING auction
+            WHERE
+                auction.id = auction_order.auction_id
+                AND auction.tournament_id = $1
+            ",
+            "
+            DELETE FROM auction
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM roster_moves
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM waiver_claims
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM waiver_priority
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM survivor_eliminations
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM leaderboard_snapshots
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_users
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_positions
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_invitations
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_waitlist
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM tournament_activity
+            WHERE tournament_id = $1
+            ",
+            "
+            DELETE FROM season_tournaments
+            WHERE tournament_id = $1
+            ",
             "
             DELETE FROM tournament
             WHERE id = $1
             ",
-        )
-        .bind(tournament_id)
-        .execute(&pool)
-        .await?;
+        ];
 
-        return Ok(());
+        for statement in statements {
+            sqlx::query(statement)
+                .bind(tournament_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
     }
 
-    pub async fn delete_tournament_user(tournament_user_id: i64) -> Result<(), Error> {
+    // Removes a member along with everything they own in the league, so their drafted, won
+    // and picked up athletes become available again.
+    // The member's picks are logged as deletes before they go. Pick events are an append-only
+    // history, so they're kept after the member leaves. A commissioner removal records its
+    // activity entry in the same transaction.
+    pub async fn delete_tournament_user(
+        tournament_user_id: i64,
+        source: &str,
+        activity: Option<&LeagueActivityData>,
+    ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
 
-        let _ = sqlx::query(
+        sqlx::query(
             "
-            DELETE FROM tournament_users
-            WHERE id = $1
+            INSERT INTO tournament_user_pick_events (tournament_user_id, action, source, previous_competitor_id, competitor_id, tournament_position_id, workout_id, rank, created_at)
+            SELECT
+                tournament_user_id,
+                $2,
+                $3,
+                competitor_id,
+                NULL,
+                tournament_position_id,
+                workout_id,
+                rank,
+                $4
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
             ",
         )
         .bind(tournament_user_id)
-        .execute(&pool)
+        .bind(pick_action::DELETE)
+        .bind(source)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&mut *tx)
         .await?;
 
-        return Ok(());
+        let statements = [
+            "
+            DELETE FROM tournament_user_picks
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM draft_picks
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM draft_order
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM auction_picks
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM auction_order
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM roster_moves
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM waiver_claims
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM waiver_priority
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM survivor_eliminations
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM leaderboard_snapshots
+            WHERE tournament_user_id = $1
+            ",
+            "
+            DELETE FROM tournament_users
+            WHERE id = $1
+            ",
+        ];
+
+        for statement in statements {
+            sqlx::query(statement)
+                .bind(tournament_user_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        if let Some(activity) = activity {
+            Self::insert_league_activity(&mut tx, activity).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
     }
 
-    pub async fn delete_tournament_users(tournament_id: i64) -> Result<(), Error> {
+    pub async fn delete_waitlist_entry(waitlist_id: i64) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
         let _ = sqlx::query(
             "
-            DELETE
-            FROM tournament_users
-            WHERE tournament_users.tournament_id = $1
+            DELETE FROM tournament_waitlist
+            WHERE id = $1
             ",
         )
-        .bind(tournament_id)
+        .bind(waitlist_id)
         .execute(&pool)
         .await?;
 
-        return Ok(());
+        Ok(())
     }
 
-    pub async fn delete_tournament_positions(tournament_id: i64) -> Result<(), Error> {
+    pub async fn update_pick_competitor(
+        tournament_user_pick_id: i64,
+        competitor_id: i64,
+    ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
-        let _ = sqlx::query(
+        sqlx::query(
             "
-            DELETE
-            FROM tournament_positions
-            WHERE tournament_positions.tournament_id = $1
+            UPDATE tournament_user_picks
+            SET competitor_id = $2, last_updated = $3
+            WHERE id = $1
             ",
         )
-        .bind(tournament_id)
+        .bind(tournament_user_pick_id)
+        .bind(competitor_id)
+        .bind(format!("{}", chrono::Utc::now()))
         .execute(&pool)
         .await?;
 
         return Ok(());
     }
 
-    pub async fn delete_tournament_picks(tournament_id: i64) -> Result<(), Error> {
+    // Confidence weights and the captain flag belong to the athlete, so they swap along with them.
+    pub async fn swap_pick_attributes(
+        previous_pick_id: i64,
+        next_pick_id: i64,
+    ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
-        let _ = sqlx::query(
+        sqlx::query(
             "
-            DELETE
-            FROM tournament_user_picks
-            USING tournament_users
-            WHERE
-                tournament_users.id = tournament_user_picks.tournament_user_id
-                AND tournament_users.tournament_id = $1
+            UPDATE tournament_user_picks tup
+            SET confidence = other.confidence, is_captain = other.is_captain
+            FROM tournament_user_picks other
+            WHERE (tup.id = $1 AND other.id = $2)
+                OR (tup.id = $2 AND other.id = $1)
             ",
         )
-        .bind(tournament_id)
+        .bind(previous_pick_id)
+        .bind(next_pick_id)
         .execute(&pool)
         .await?;
 
-        return Ok(());
+        Ok(())
     }
 
-    pub async fn delete_tournament_user_picks(tournament_user_id: i64) -> Result<(), Error> {
+    // Returns the member and workout a pick belongs to. Top-N picks have no workout.
+    pub async fn fetch_pick_scope(
+        tournament_user_pick_id: i64,
+    ) -> Result<(i64, Option<i64>), Error> {
         let pool = DataClient::connect().await?;
 
-        let _ = sqlx::query(
+        let res = sqlx::query(
             "
-            DELETE FROM tournament_user_picks
-            USING tournament_users
+            SELECT
+                tournament_user_id,
+                workout_id
+            FROM
+                tournament_user_picks
             WHERE
-                tournament_users.id = tournament_user_picks.tournament_user_id
-                AND tournament_users.id = $1
+                id = $1
             ",
         )
-        .bind(tournament_user_id)
-        .execute(&pool)
+        .bind(tournament_user_pick_id)
+        .map(|row: PgRow| (row.get("tournament_user_id"), row.get("workout_id")))
+        .fetch_one(&pool)
         .await?;
 
-        return Ok(());
+        Ok(res)
     }
 
-    pub async fn update_pick_competitor(
+    // Moves the captain flag to the pick, clearing it from every other pick in the same scope.
+    pub async fn update_pick_captain(
+        tournament_user_id: i64,
         tournament_user_pick_id: i64,
-        competitor_id: i64,
     ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
         sqlx::query(
             "
             UPDATE tournament_user_picks
-            SET competitor_id = $2, last_updated = $3
-            WHERE id = $1
+            SET is_captain = (id = $2)
+            WHERE tournament_user_id = $1
+                AND workout_id IS NOT DISTINCT FROM (
+                    SELECT workout_id FROM tournament_user_picks WHERE id = $2
+                )
             ",
         )
+        .bind(tournament_user_id)
         .bind(tournament_user_pick_id)
-        .bind(competitor_id)
-        .bind(format!("{}", chrono::Utc::now()))
         .execute(&pool)
         .await?;
 
-        return Ok(());
+        Ok(())
     }
 
     pub async fn swap_user_league_pick(
@@ -1830,13 +2930,15 @@ impl LeagueRepository {
         competitor_id: i64,
         rank: i64,
         tournament_position_id: i64,
+        confidence: Option<i64>,
+        is_captain: bool,
     ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
         sqlx::query(
             "
-            INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, rank, tournament_position_id, last_updated)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, rank, tournament_position_id, last_updated, confidence, is_captain)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ",
         )
             .bind(tournament_user_id)
@@ -1844,6 +2946,8 @@ impl LeagueRepository {
             .bind(rank)
             .bind(tournament_position_id)
             .bind(format!("{}", chrono::Utc::now()))
+            .bind(confidence)
+            .bind(is_captain)
             .execute(&pool)
             .await?;
 
@@ -1855,13 +2959,14 @@ impl LeagueRepository {
         competitor_id: i64,
         workout_id: i64,
         tournament_position_id: i64,
+        is_captain: bool,
     ) -> Result<(), Error> {
         let pool = DataClient::connect().await?;
 
         sqlx::query(
             "
-            INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, workout_id, tournament_position_id, last_updated)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, workout_id, tournament_position_id, last_updated, is_captain)
+            VALUES ($1, $2, $3, $4, $5, $6)
             ",
         )
         .bind(tournament_user_id)
@@ -1869,6 +2974,7 @@ impl LeagueRepository {
         .bind(workout_id)
         .bind(tournament_position_id)
         .bind(format!("{}", chrono::Utc::now()))
+        .bind(is_captain)
         .execute(&pool)
         .await?;
 
@@ -1882,9 +2988,9 @@ impl LeagueRepository {
             "
             INSERT INTO
                 tournament
-            (competition_id, name, tournament_type_id, is_private, passcode, commissioner_id, pick_count, logo)
+            (competition_id, name, tournament_type_id, is_private, passcode, commissioner_id, pick_count, logo, max_entries)
             VALUES
-                ($1, $2, $3, $4, $5, $6, $7, $8)
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             RETURNING
                 id
             ",
@@ -1897,6 +3003,7 @@ impl LeagueRepository {
         .bind(tournament.commissioner_id as i64)
         .bind(tournament.pick_count.unwrap_or(0i64))
             .bind(if tournament.tournament_type_id == 1 { "https://storage.googleapis.com/heat1-assets-pub/tournament/Top%2010%20Heat%201.png" } else { "https://storage.googleapis.com/heat1-assets-pub/tournament/Heat1%20shotcaller.png"})
+        .bind(tournament.max_entries)
         .fetch_one(&pool)
         .await?;
 
@@ -2313,4 +3420,159 @@ impl LeagueRepository {
 
         return Ok(());
     }
+
+    pub async fn update_invitation_status(invitation_id: i64, status: &str) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament_invitations
+            SET status = $2
+            WHERE id = $1
+            ",
+        )
+        .bind(invitation_id)
+        .bind(status)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_name(
+        tournament_id: i64,
+        name: &str,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament
+            SET name = $2
+            WHERE id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .bind(name.trim())
+        .execute(&mut *tx)
+        .await?;
+
+        Self::insert_league_activity(&mut tx, activity).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_logo(
+        tournament_id: i64,
+        logo: &str,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament
+            SET logo = $2
+            WHERE id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .bind(logo.trim())
+        .execute(&mut *tx)
+        .await?;
+
+        Self::insert_league_activity(&mut tx, activity).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_privacy(
+        tournament_id: i64,
+        is_private: bool,
+        passcode: Option<String>,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament
+            SET is_private = $2, passcode = $3
+            WHERE id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .bind(is_private)
+        .bind(passcode)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::insert_league_activity(&mut tx, activity).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_commissioner(
+        tournament_id: i64,
+        commissioner_id: i64,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament
+            SET commissioner_id = $2
+            WHERE id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .bind(commissioner_id)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::insert_league_activity(&mut tx, activity).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn update_tournament_max_entries(
+        tournament_id: i64,
+        max_entries: Option<i64>,
+        activity: &LeagueActivityData,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tournament
+            SET max_entries = $2
+            WHERE id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .bind(max_entries)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::insert_league_activity(&mut tx, activity).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,137 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::request_models::{LineupShotCallerPick, LineupTopPick};
+use crate::handlers::league::response_models::PickEventData;
+use crate::repositories::pick_event::PickEventRepository;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct LineupRepository;
+
+impl LineupRepository {
+    // Replaces the member's whole Top-N lineup and records its pick events in one
+    // transaction. The captain carries over when the same athlete is still in the lineup.
+    pub async fn replace_top_lineup(
+        tournament_user_id: i64,
+        picks: &[LineupTopPick],
+        events: &[PickEventData],
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        let captain_id: Option<i64> = sqlx::query(
+            "
+            SELECT
+                competitor_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND is_captain = true
+            LIMIT 1
+            ",
+        )
+        .bind(tournament_user_id)
+        .map(|row: PgRow| row.get("competitor_id"))
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tournament_user_picks
+            WHERE tournament_user_id = $1
+            ",
+        )
+        .bind(tournament_user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        for pick in picks {
+            sqlx::query(
+                "
+                INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, rank, tournament_position_id, last_updated, is_captain)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ",
+            )
+            .bind(tournament_user_id)
+            .bind(pick.competitor_id)
+            .bind(pick.rank)
+            .bind(pick.tournament_position_id)
+            .bind(format!("{}", chrono::Utc::now()))
+            .bind(captain_id == Some(pick.competitor_id))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        PickEventRepository::insert_pick_events(&mut tx, events).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // Only the given (unlocked) workouts are replaced, picks for locked workouts are kept.
+    // Each workout's captain carries over when the same athlete is still picked for it.
+    pub async fn replace_shotcaller_lineup(
+        tournament_user_id: i64,
+        workout_ids: &[i64],
+        picks: &[LineupShotCallerPick],
+        events: &[PickEventData],
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        let captains: Vec<(i64, i64)> = sqlx::query(
+            "
+            SELECT
+                workout_id,
+                competitor_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND workout_id = ANY($2)
+                AND is_captain = true
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_ids)
+        .map(|row: PgRow| (row.get("workout_id"), row.get("competitor_id")))
+        .fetch_all(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tournament_user_picks
+            WHERE tournament_user_id = $1
+                AND workout_id = ANY($2)
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        for pick in picks {
+            sqlx::query(
+                "
+                INSERT INTO tournament_user_picks (tournament_user_id, competitor_id, workout_id, tournament_position_id, last_updated, is_captain)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ",
+            )
+            .bind(tournament_user_id)
+            .bind(pick.competitor_id)
+            .bind(pick.workout_id)
+            .bind(pick.tournament_position_id)
+            .bind(format!("{}", chrono::Utc::now()))
+            .bind(captains.contains(&(pick.workout_id, pick.competitor_id)))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        PickEventRepository::insert_pick_events(&mut tx, events).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,24 @@
 pub mod app_user;
+pub mod auction;
 pub mod competitor;
+pub mod competitor_status;
+pub mod confidence;
+pub mod consensus;
 pub mod crossfit;
+pub mod draft;
+pub mod global_leaderboard;
+pub mod hindsight;
 pub mod league;
+pub mod lineup;
 pub mod news;
 pub mod open;
+pub mod pick_event;
+pub mod pick_validation;
+pub mod podium;
 pub mod props;
+pub mod roster;
+pub mod salary;
+pub mod season;
+pub mod snapshot;
+pub mod survivor;
+pub mod waiver;
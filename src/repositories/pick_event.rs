@@ -0,0 +1,170 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::{PickEventData, PickEventResponse, PickSlotData};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Postgres, Row, Transaction};
+
+pub struct PickEventRepository;
+
+impl PickEventRepository {
+    // Returns the pick's slot and competitor, read before the pick is changed or removed.
+    pub async fn fetch_pick_slot(
+        tournament_user_pick_id: i64,
+    ) -> Result<(PickSlotData, i64), Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tournament_user_id,
+                tournament_position_id,
+                workout_id,
+                rank,
+                competitor_id
+            FROM
+                tournament_user_picks
+            WHERE
+                id = $1
+            ",
+        )
+        .bind(tournament_user_pick_id)
+        .map(|row: PgRow| {
+            (
+                PickSlotData {
+                    tournament_user_id: row.get("tournament_user_id"),
+                    tournament_position_id: row.get("tournament_position_id"),
+                    workout_id: row.get("workout_id"),
+                    rank: row.get("rank"),
+                },
+                row.get("competitor_id"),
+            )
+        })
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Events are only ever appended, there is no update or delete for this table.
+    pub async fn insert_pick_event(
+        action: &str,
+        source: &str,
+        slot: &PickSlotData,
+        previous_competitor_id: Option<i64>,
+        competitor_id: Option<i64>,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO tournament_user_pick_events (tournament_user_id, action, source, previous_competitor_id, competitor_id, tournament_position_id, workout_id, rank, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ",
+        )
+        .bind(slot.tournament_user_id)
+        .bind(action)
+        .bind(source)
+        .bind(previous_competitor_id)
+        .bind(competitor_id)
+        .bind(slot.tournament_position_id)
+        .bind(slot.workout_id)
+        .bind(slot.rank)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Writes events as part of the caller's transaction, so they only land with the picks
+    // they describe.
+    pub(crate) async fn insert_pick_events(
+        tx: &mut Transaction<'_, Postgres>,
+        events: &[PickEventData],
+    ) -> Result<(), Error> {
+        for event in events {
+            sqlx::query(
+                "
+                INSERT INTO tournament_user_pick_events (tournament_user_id, action, source, previous_competitor_id, competitor_id, tournament_position_id, workout_id, rank, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ",
+            )
+            .bind(event.slot.tournament_user_id)
+            .bind(&event.action)
+            .bind(&event.source)
+            .bind(event.previous_competitor_id)
+            .bind(event.competitor_id)
+            .bind(event.slot.tournament_position_id)
+            .bind(event.slot.workout_id)
+            .bind(event.slot.rank)
+            .bind(format!("{}", chrono::Utc::now()))
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn fetch_pick_events(
+        tournament_id: i64,
+        tournament_user_id: i64,
+    ) -> Result<Vec<PickEventResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                pe.id,
+                pe.action,
+                pe.source,
+                pe.previous_competitor_id,
+                pc.first_name || ' ' || pc.last_name AS previous_competitor_name,
+                pe.competitor_id,
+                c.first_name || ' ' || c.last_name AS competitor_name,
+                pe.tournament_position_id,
+                pe.workout_id,
+                w.name AS workout_name,
+                pe.rank,
+                pe.created_at
+            FROM
+                tournament_user_pick_events pe
+            JOIN
+                tournament_users tu
+                ON tu.id = pe.tournament_user_id
+            LEFT JOIN
+                competitor pc
+                ON pc.id = pe.previous_competitor_id
+            LEFT JOIN
+                competitor c
+                ON c.id = pe.competitor_id
+            LEFT JOIN
+                workouts w
+                ON w.id = pe.workout_id
+            WHERE
+                tu.tournament_id = $1
+                AND pe.tournament_user_id = $2
+            ORDER BY
+                pe.id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .map(|row: PgRow| PickEventResponse {
+            id: row.get("id"),
+            action: row.get("action"),
+            source: row.get("source"),
+            previous_competitor_id: row.get("previous_competitor_id"),
+            previous_competitor_name: row.get("previous_competitor_name"),
+            competitor_id: row.get("competitor_id"),
+            competitor_name: row.get("competitor_name"),
+            tournament_position_id: row.get("tournament_position_id"),
+            workout_id: row.get("workout_id"),
+            workout_name: row.get("workout_name"),
+            rank: row.get("rank"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::CompetitionEntryData;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+use std::collections::HashMap;
+
+pub struct PickValidationRepository;
+
+impl PickValidationRepository {
+    // Only competitors entered in the member's competition are returned, keyed by competitor id.
+    pub async fn fetch_competition_entries(
+        tournament_user_id: i64,
+        competitor_ids: &[i64],
+    ) -> Result<HashMap<i64, CompetitionEntryData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                cc.competitor_id,
+                c.gender_id,
+                cc.position_id
+            FROM
+                tournament_users tu
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            JOIN
+                competition_competitor cc
+                ON cc.competition_id = t.competition_id
+            JOIN
+                competitor c
+                ON c.id = cc.competitor_id
+            WHERE
+                tu.id = $1
+                AND cc.competitor_id = ANY($2)
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(competitor_ids)
+        .map(|row: PgRow| CompetitionEntryData {
+            competitor_id: row.get("competitor_id"),
+            gender_id: row.get("gender_id"),
+            position_id: row.get("position_id"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res.into_iter().map(|e| (e.competitor_id, e)).collect())
+    }
+
+    // The positions a member already has the competitor in for a per-workout pick.
+    pub async fn fetch_workout_competitor_position_ids(
+        tournament_user_id: i64,
+        workout_id: i64,
+        competitor_id: i64,
+    ) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tournament_position_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND workout_id = $2
+                AND competitor_id = $3
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_id)
+        .bind(competitor_id)
+        .map(|row: PgRow| row.get("tournament_position_id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,205 @@
+use crate::data::constants::podium_points;
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::{LeaderboardEntry, PodiumPick};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct PodiumRepository;
+
+impl PodiumRepository {
+    // Workout placements are ranked per gender from score.points, so ties share a placement.
+    pub async fn fetch_podium_leaderboard(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH placements AS (
+                SELECT
+                    s.competitor_id,
+                    s.ordinal,
+                    RANK() OVER (PARTITION BY s.ordinal, c.gender_id ORDER BY s.points DESC) AS placement
+                FROM score s
+                JOIN competitor c ON c.id = s.competitor_id
+                WHERE s.competition_id = $2
+                    AND s.points IS NOT NULL
+            ),
+            pick_points AS (
+                SELECT
+                    tup.tournament_user_id,
+                    CASE
+                        WHEN p.placement = tp.ordinal THEN $3
+                        WHEN ABS(p.placement - tp.ordinal) = 1 THEN $4
+                        ELSE 0::double precision
+                    END AS points
+                FROM tournament_user_picks tup
+                JOIN tournament_positions tp ON tp.id = tup.tournament_position_id
+                JOIN workouts w ON w.id = tup.workout_id
+                LEFT JOIN placements p
+                    ON p.competitor_id = tup.competitor_id
+                    AND p.ordinal = w.ordinal
+                WHERE tp.tournament_id = $1
+                    AND tup.is_invalid = false
+            )
+            SELECT
+                tu.id as tournament_user_id,
+                au.username,
+                au.profile_url,
+                SUM(pp.points) AS points,
+                COUNT(*) FILTER (WHERE pp.points = $3) AS exact_picks,
+                RANK() OVER (
+                    ORDER BY COALESCE(SUM(pp.points), 0::double precision) DESC,
+                    COUNT(*) FILTER (WHERE pp.points = $3) DESC
+                ) AS ordinal
+            FROM tournament_users tu
+                JOIN app_user au
+                    ON au.id = tu.user_id
+                LEFT JOIN pick_points pp
+                    ON pp.tournament_user_id = tu.id
+            WHERE
+                tu.tournament_id = $1
+            GROUP BY
+                tu.id,
+                au.username,
+                au.profile_url
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .bind(podium_points::EXACT)
+        .bind(podium_points::NEAR)
+        .map(|row: PgRow| LeaderboardEntry {
+            tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
+            display_name: row.get("username"),
+            avatar: row.get("profile_url"),
+            points: row.try_get("points").unwrap_or(0.0),
+            event_wins: row.get("exact_picks"),
+            ordinal: row.get("ordinal"),
+            events: vec![],
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Only picks for workouts that have started are returned, so a member's picks stay hidden
+    // in matchups until they lock.
+    pub async fn fetch_podium_picks(
+        tournament_id: i64,
+        tournament_user_id: i64,
+    ) -> Result<Vec<PodiumPick>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH placements AS (
+                SELECT
+                    s.competitor_id,
+                    s.ordinal,
+                    RANK() OVER (PARTITION BY s.ordinal, c.gender_id ORDER BY s.points DESC) AS placement
+                FROM score s
+                JOIN competitor c ON c.id = s.competitor_id
+                JOIN tournament t ON t.competition_id = s.competition_id
+                WHERE t.id = $1
+                    AND s.points IS NOT NULL
+            )
+            SELECT
+                tup.workout_id,
+                tup.tournament_position_id,
+                tp.ordinal AS slot,
+                c.gender_id,
+                tup.competitor_id,
+                c.first_name,
+                c.last_name,
+                p.placement,
+                CASE
+                    WHEN p.placement = tp.ordinal THEN $3
+                    WHEN ABS(p.placement - tp.ordinal) = 1 THEN $4
+                    ELSE 0::double precision
+                END AS points
+            FROM
+                tournament_user_picks tup
+            JOIN
+                tournament_positions tp
+                ON tp.id = tup.tournament_position_id
+            JOIN
+                competitor c
+                ON c.id = tup.competitor_id
+            JOIN
+                workouts w
+                ON w.id = tup.workout_id
+            LEFT JOIN
+                placements p
+                ON p.competitor_id = tup.competitor_id
+                AND p.ordinal = w.ordinal
+            WHERE
+                tp.tournament_id = $1
+                AND tup.tournament_user_id = $2
+                AND tup.is_invalid = false
+                AND (w.is_active OR w.is_complete)
+            ORDER BY
+                w.ordinal,
+                c.gender_id,
+                tp.ordinal
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .bind(podium_points::EXACT)
+        .bind(podium_points::NEAR)
+        .map(|row: PgRow| PodiumPick {
+            workout_id: row.get("workout_id"),
+            tournament_position_id: row.get("tournament_position_id"),
+            slot: row.get("slot"),
+            gender_id: row.get("gender_id"),
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            placement: row.get("placement"),
+            points: row.get("points"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Each slot holds one pick per gender, so the previous pick is matched on the new competitor's gender.
+    pub async fn fetch_podium_pick_id(
+        tournament_user_id: i64,
+        workout_id: i64,
+        tournament_position_id: i64,
+        competitor_id: i64,
+    ) -> Result<Option<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.id
+            FROM
+                tournament_user_picks tup
+            JOIN
+                competitor c
+                ON c.id = tup.competitor_id
+            WHERE
+                tup.tournament_user_id = $1
+                AND tup.workout_id = $2
+                AND tup.tournament_position_id = $3
+                AND c.gender_id = (SELECT gender_id FROM competitor WHERE id = $4)
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_id)
+        .bind(tournament_position_id)
+        .bind(competitor_id)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_optional(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
@@ -245,7 +245,8 @@ impl PropsRepository {
                 competition.is_active,
                 competition.is_complete,
                 tournament.tournament_type_id,
-                tournament.pick_count
+                tournament.pick_count,
+                tournament.max_entries
             FROM
                 tournament_users
             JOIN
@@ -276,6 +277,9 @@ impl PropsRepository {
             tournament_type_id: row.get::<i64, _>("tournament_type_id") as u64,
             pick_count: row.get::<Option<i64>, _>("pick_count"),
             positions: vec![],
+            invitation_id: None,
+            max_entries: row.get("max_entries"),
+            waitlist_position: None,
         })
         .fetch_optional(&pool)
         .await?;
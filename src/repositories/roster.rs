@@ -0,0 +1,256 @@
+use crate::data::constants::waiver_status;
+use crate::data::data_client::DataClient;
+use crate::handlers::draft::response_models::RosterEntry;
+use crate::handlers::league::response_models::LeaderboardEntry;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+// The current rosters, counting draft or auction picks and every roster move since.
+const ROSTERS_QUERY: &str = "
+    WITH roster AS (
+        SELECT dp.tournament_user_id, dp.competitor_id, 0::bigint AS from_ordinal, 0::bigint AS move_id
+        FROM draft_picks dp
+        JOIN draft d ON d.id = dp.draft_id
+        WHERE d.tournament_id = $1
+        UNION ALL
+        SELECT ap.tournament_user_id, ap.competitor_id, 0::bigint AS from_ordinal, 0::bigint AS move_id
+        FROM auction_picks ap
+        JOIN auction a ON a.id = ap.auction_id
+        WHERE a.tournament_id = $1
+        UNION ALL
+        SELECT rm.tournament_user_id, rm.add_competitor_id, rm.effective_ordinal, rm.id
+        FROM roster_moves rm
+        WHERE rm.tournament_id = $1
+    )
+    SELECT
+        r.tournament_user_id,
+        r.competitor_id,
+        r.from_ordinal,
+        c.first_name,
+        c.last_name,
+        c.gender_id,
+        cc.position_id
+    FROM
+        roster r
+    JOIN
+        competitor c
+        ON c.id = r.competitor_id
+    JOIN
+        tournament t
+        ON t.id = $1
+    LEFT JOIN
+        competition_competitor cc
+        ON cc.competitor_id = r.competitor_id
+        AND cc.competition_id = t.competition_id
+    WHERE
+        NOT EXISTS (
+            SELECT 1
+            FROM roster_moves rm
+            WHERE rm.tournament_id = $1
+                AND rm.tournament_user_id = r.tournament_user_id
+                AND rm.drop_competitor_id = r.competitor_id
+                AND rm.id > r.move_id
+        )
+    ORDER BY
+        r.tournament_user_id,
+        r.from_ordinal
+    ";
+
+pub struct RosterRepository;
+
+impl RosterRepository {
+    fn map_roster_entry(row: PgRow) -> RosterEntry {
+        RosterEntry {
+            tournament_user_id: row.get("tournament_user_id"),
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            gender_id: row.get("gender_id"),
+            position_id: row.get::<Option<i64>, _>("position_id").unwrap_or(0),
+            from_ordinal: row.get("from_ordinal"),
+        }
+    }
+
+    pub async fn fetch_rosters(tournament_id: i64) -> Result<Vec<RosterEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(ROSTERS_QUERY)
+            .bind(tournament_id)
+            .map(Self::map_roster_entry)
+            .fetch_all(&pool)
+            .await?;
+
+        Ok(res)
+    }
+
+    // Each competitor only scores for a member in the events between being rostered and being dropped.
+    pub async fn fetch_roster_leaderboard(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            WITH roster AS (
+                SELECT dp.tournament_user_id, dp.competitor_id, 0::bigint AS from_ordinal, 0::bigint AS move_id
+                FROM draft_picks dp
+                JOIN draft d ON d.id = dp.draft_id
+                WHERE d.tournament_id = $1
+                UNION ALL
+                SELECT ap.tournament_user_id, ap.competitor_id, 0::bigint AS from_ordinal, 0::bigint AS move_id
+                FROM auction_picks ap
+                JOIN auction a ON a.id = ap.auction_id
+                WHERE a.tournament_id = $1
+                UNION ALL
+                SELECT rm.tournament_user_id, rm.add_competitor_id, rm.effective_ordinal, rm.id
+                FROM roster_moves rm
+                WHERE rm.tournament_id = $1
+            ),
+            tenure AS (
+                SELECT
+                    r.tournament_user_id,
+                    r.competitor_id,
+                    r.from_ordinal,
+                    (
+                        SELECT MIN(rm.effective_ordinal)
+                        FROM roster_moves rm
+                        WHERE rm.tournament_id = $1
+                            AND rm.tournament_user_id = r.tournament_user_id
+                            AND rm.drop_competitor_id = r.competitor_id
+                            AND rm.id > r.move_id
+                    ) AS to_ordinal
+                FROM roster r
+            )
+            SELECT
+                tu.id as tournament_user_id,
+                au.username,
+                au.profile_url,
+                SUM(s.points) AS points,
+                COUNT(*) FILTER (WHERE s.points = 100) AS exact_picks,
+                RANK() OVER (
+                    ORDER BY COALESCE(SUM(s.points), 0::double precision) DESC,
+                    COUNT(*) FILTER (WHERE s.points = 100) DESC
+                ) AS ordinal
+            FROM tournament_users tu
+                JOIN app_user au
+                    ON au.id = tu.user_id
+                LEFT JOIN tenure t
+                    ON t.tournament_user_id = tu.id
+                LEFT JOIN score s
+                    ON s.competitor_id = t.competitor_id
+                    AND s.competition_id = $2
+                    AND s.ordinal >= t.from_ordinal
+                    AND (t.to_ordinal IS NULL OR s.ordinal < t.to_ordinal)
+            WHERE
+                tu.tournament_id = $1
+            GROUP BY
+                tu.id,
+                au.username,
+                au.profile_url
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .map(|row: PgRow| LeaderboardEntry {
+            tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
+            display_name: row.get("username"),
+            avatar: row.get("profile_url"),
+            points: row.try_get("points").unwrap_or(0.0),
+            event_wins: row.get("exact_picks"),
+            ordinal: row.get("ordinal"),
+            events: vec![],
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // The tournament row is locked so concurrent moves can't both roster the same competitor.
+    // The move is checked against the rosters under that lock and returns false when the dropped
+    // competitor is no longer on the member's roster or the added one is already rostered. A
+    // waiver claim is marked awarded in the same transaction, false when it's no longer pending.
+    pub async fn insert_roster_move(
+        tournament_id: i64,
+        tournament_user_id: i64,
+        drop_competitor_id: i64,
+        add_competitor_id: i64,
+        effective_ordinal: i64,
+        source: &str,
+        waiver_claim_id: Option<i64>,
+    ) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament
+            WHERE
+                id = $1
+            FOR UPDATE
+            ",
+        )
+        .bind(tournament_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let rosters = sqlx::query(ROSTERS_QUERY)
+            .bind(tournament_id)
+            .map(Self::map_roster_entry)
+            .fetch_all(&mut *tx)
+            .await?;
+
+        let is_dropped_rostered = rosters.iter().any(|r| {
+            r.tournament_user_id == tournament_user_id && r.competitor_id == drop_competitor_id
+        });
+        let is_added_rostered = rosters.iter().any(|r| r.competitor_id == add_competitor_id);
+
+        if !is_dropped_rostered || is_added_rostered {
+            return Ok(false);
+        }
+
+        if let Some(waiver_claim_id) = waiver_claim_id {
+            let res = sqlx::query(
+                "
+                UPDATE waiver_claims
+                SET status = $2
+                WHERE id = $1
+                    AND status = $3
+                ",
+            )
+            .bind(waiver_claim_id)
+            .bind(waiver_status::AWARDED)
+            .bind(waiver_status::PENDING)
+            .execute(&mut *tx)
+            .await?;
+
+            if res.rows_affected() == 0 {
+                return Ok(false);
+            }
+        }
+
+        sqlx::query(
+            "
+            INSERT INTO roster_moves (tournament_id, tournament_user_id, drop_competitor_id, add_competitor_id, effective_ordinal, source, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .bind(drop_competitor_id)
+        .bind(add_competitor_id)
+        .bind(effective_ordinal)
+        .bind(source)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::data::data_client::DataClient;
+use crate::handlers::league::response_models::{LeaderboardEntry, SalaryData, SalaryPickData};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct SalaryRepository;
+
+impl SalaryRepository {
+    // Pick percentage is averaged across the competition's workouts.
+    pub async fn fetch_salary_data(competition_id: i64) -> Result<Vec<SalaryData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                cc.competitor_id,
+                c.first_name,
+                c.last_name,
+                c.gender_id,
+                cc.position_id,
+                cc.adp,
+                cc.is_withdrawn,
+                COALESCE(AVG(cpp.pick_percentage), 0::double precision) AS pick_percentage
+            FROM
+                competition_competitor cc
+            JOIN
+                competitor c
+                ON c.id = cc.competitor_id
+            LEFT JOIN
+                competitor_pick_percentages cpp
+                ON cpp.competitor_id = cc.competitor_id
+                AND cpp.competition_id = cc.competition_id
+            WHERE
+                cc.competition_id = $1
+            GROUP BY
+                cc.competitor_id,
+                c.first_name,
+                c.last_name,
+                c.gender_id,
+                cc.position_id,
+                cc.adp,
+                cc.is_withdrawn
+            ",
+        )
+        .bind(competition_id)
+        .map(|row: PgRow| SalaryData {
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            gender_id: row.get("gender_id"),
+            position_id: row.get("position_id"),
+            adp: row.get("adp"),
+            pick_percentage: row.get("pick_percentage"),
+            is_withdrawn: row.get("is_withdrawn"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_lineup(tournament_user_id: i64) -> Result<Vec<SalaryPickData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                competitor_id,
+                tournament_position_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND is_invalid = false
+            ",
+        )
+        .bind(tournament_user_id)
+        .map(|row: PgRow| SalaryPickData {
+            id: row.get("id"),
+            competitor_id: row.get("competitor_id"),
+            tournament_position_id: row.get("tournament_position_id"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // Lineups are set for the whole competition, so every workout score counts.
+    pub async fn fetch_salary_leaderboard(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tu.id as tournament_user_id,
+                au.username,
+                au.profile_url,
+                SUM(s.points) AS points,
+                COUNT(*) FILTER (WHERE s.points = 100) AS exact_picks,
+                RANK() OVER (
+                    ORDER BY COALESCE(SUM(s.points), 0::double precision) DESC,
+                    COUNT(*) FILTER (WHERE s.points = 100) DESC
+                ) AS ordinal
+            FROM tournament_users tu
+                JOIN app_user au
+                    ON au.id = tu.user_id
+                LEFT JOIN tournament_user_picks tup
+                    ON tup.tournament_user_id = tu.id
+                    AND tup.is_invalid = false
+                LEFT JOIN score s
+                    ON s.competitor_id = tup.competitor_id
+                    AND s.competition_id = $2
+            WHERE
+                tu.tournament_id = $1
+            GROUP BY
+                tu.id,
+                au.username,
+                au.profile_url
+            ",
+        )
+        .bind(tournament_id)
+        .bind(competition_id)
+        .map(|row: PgRow| LeaderboardEntry {
+            tournament_user_id: row.get::<i64, _>("tournament_user_id") as u64,
+            display_name: row.get("username"),
+            avatar: row.get("profile_url"),
+            points: row.try_get("points").unwrap_or(0.0),
+            event_wins: row.get("exact_picks"),
+            ordinal: row.get("ordinal"),
+            events: vec![],
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
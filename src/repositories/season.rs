@@ -0,0 +1,167 @@
+use crate::data::data_client::DataClient;
+use crate::data::models::season::Season;
+use crate::data::models::season_tournament::SeasonTournament;
+use crate::handlers::season::response_models::SeasonMemberData;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct SeasonRepository;
+
+impl SeasonRepository {
+    pub async fn fetch_season(season_id: i64) -> Result<Season, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                name,
+                commissioner_id,
+                created_at
+            FROM
+                season
+            WHERE
+                id = $1
+            ",
+        )
+        .bind(season_id)
+        .map(|row: PgRow| Season {
+            id: row.get("id"),
+            name: row.get("name"),
+            commissioner_id: row.get("commissioner_id"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn insert_season(name: &str, commissioner_id: i64) -> Result<i64, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            INSERT INTO season (name, commissioner_id, created_at)
+            VALUES ($1, $2, $3)
+            RETURNING id
+            ",
+        )
+        .bind(name)
+        .bind(commissioner_id)
+        .bind(format!("{}", chrono::Utc::now()))
+        .map(|row: PgRow| row.get("id"))
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_season_tournaments(season_id: i64) -> Result<Vec<SeasonTournament>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                st.season_id,
+                st.tournament_id,
+                t.competition_id,
+                st.weight
+            FROM
+                season_tournaments st
+            JOIN
+                tournament t
+                ON t.id = st.tournament_id
+            WHERE
+                st.season_id = $1
+            ORDER BY
+                t.competition_id
+            ",
+        )
+        .bind(season_id)
+        .map(|row: PgRow| SeasonTournament {
+            season_id: row.get("season_id"),
+            tournament_id: row.get("tournament_id"),
+            competition_id: row.get("competition_id"),
+            weight: row.get("weight"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn upsert_season_tournament(
+        season_id: i64,
+        tournament_id: i64,
+        weight: f64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO season_tournaments (season_id, tournament_id, weight)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (season_id, tournament_id)
+            DO UPDATE SET weight = EXCLUDED.weight
+            ",
+        )
+        .bind(season_id)
+        .bind(tournament_id)
+        .bind(weight)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_season_tournament(season_id: i64, tournament_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            DELETE FROM season_tournaments
+            WHERE
+                season_id = $1
+                AND tournament_id = $2
+            ",
+        )
+        .bind(season_id)
+        .bind(tournament_id)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Maps each league entry in the season back to the app user who owns it.
+    pub async fn fetch_season_members(season_id: i64) -> Result<Vec<SeasonMemberData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tu.tournament_id,
+                tu.id AS tournament_user_id,
+                tu.user_id
+            FROM
+                season_tournaments st
+            JOIN
+                tournament_users tu
+                ON tu.tournament_id = st.tournament_id
+            WHERE
+                st.season_id = $1
+            ",
+        )
+        .bind(season_id)
+        .map(|row: PgRow| SeasonMemberData {
+            tournament_id: row.get("tournament_id"),
+            tournament_user_id: row.get("tournament_user_id"),
+            user_id: row.get("user_id"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
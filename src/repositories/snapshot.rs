@@ -0,0 +1,108 @@
+use crate::data::data_client::DataClient;
+use crate::data::models::leaderboard_snapshot::LeaderboardSnapshot;
+use crate::handlers::league::response_models::LeaderboardEntry;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct SnapshotRepository;
+
+impl SnapshotRepository {
+    pub async fn fetch_competition_tournament_ids(competition_id: i64) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament
+            WHERE
+                competition_id = $1
+            ",
+        )
+        .bind(competition_id)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    // A league's entries are written in one statement so a snapshot is never partially stored.
+    pub async fn insert_leaderboard_snapshot(
+        tournament_id: i64,
+        ordinal: i64,
+        source: &str,
+        leaderboard: &[LeaderboardEntry],
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        let tournament_user_ids: Vec<i64> = leaderboard
+            .iter()
+            .map(|e| e.tournament_user_id as i64)
+            .collect();
+        let points: Vec<f64> = leaderboard.iter().map(|e| e.points).collect();
+        let ranks: Vec<i64> = leaderboard.iter().map(|e| e.ordinal).collect();
+
+        sqlx::query(
+            "
+            INSERT INTO leaderboard_snapshots (tournament_id, tournament_user_id, ordinal, source, points, rank, created_at)
+            SELECT $1, tournament_user_id, $2, $3, points, rank, $7
+            FROM UNNEST($4::bigint[], $5::double precision[], $6::bigint[])
+                AS s(tournament_user_id, points, rank)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(ordinal)
+        .bind(source)
+        .bind(tournament_user_ids)
+        .bind(points)
+        .bind(ranks)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn fetch_leaderboard_snapshots(
+        tournament_id: i64,
+    ) -> Result<Vec<LeaderboardSnapshot>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                tournament_id,
+                tournament_user_id,
+                ordinal,
+                source,
+                points,
+                rank,
+                created_at
+            FROM
+                leaderboard_snapshots
+            WHERE
+                tournament_id = $1
+            ORDER BY
+                id
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| LeaderboardSnapshot {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            tournament_user_id: row.get("tournament_user_id"),
+            ordinal: row.get("ordinal"),
+            source: row.get("source"),
+            points: row.get("points"),
+            rank: row.get("rank"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+}
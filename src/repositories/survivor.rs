@@ -0,0 +1,206 @@
+use crate::data::constants::tournament_type;
+use crate::data::data_client::DataClient;
+use crate::data::models::survivor_elimination::SurvivorElimination;
+use crate::handlers::league::response_models::SurvivorPickData;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct SurvivorRepository;
+
+impl SurvivorRepository {
+    pub async fn fetch_survivor_tournament_ids(competition_id: i64) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament
+            WHERE
+                competition_id = $1
+                AND tournament_type_id = $2
+            ",
+        )
+        .bind(competition_id)
+        .bind(tournament_type::SURVIVOR)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_survivor_picks(tournament_id: i64) -> Result<Vec<SurvivorPickData>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tup.tournament_user_id,
+                tup.workout_id,
+                w.ordinal,
+                tup.competitor_id,
+                c.first_name,
+                c.last_name,
+                s.points
+            FROM
+                tournament_users tu
+            JOIN
+                tournament t
+                ON t.id = tu.tournament_id
+            JOIN
+                tournament_user_picks tup
+                ON tup.tournament_user_id = tu.id
+            JOIN
+                workouts w
+                ON w.id = tup.workout_id
+            JOIN
+                competitor c
+                ON c.id = tup.competitor_id
+            LEFT JOIN
+                score s
+                ON s.competitor_id = tup.competitor_id
+                AND s.competition_id = t.competition_id
+                AND s.ordinal = w.ordinal
+            WHERE
+                tu.tournament_id = $1
+                AND tup.is_invalid = false
+            ORDER BY
+                w.ordinal
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| SurvivorPickData {
+            tournament_user_id: row.get("tournament_user_id"),
+            workout_id: row.get("workout_id"),
+            ordinal: row.get("ordinal"),
+            competitor_id: row.get("competitor_id"),
+            first_name: row.get("first_name"),
+            last_name: row.get("last_name"),
+            points: row.get("points"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_survivor_eliminations(
+        tournament_id: i64,
+    ) -> Result<Vec<SurvivorElimination>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                tournament_user_id,
+                ordinal,
+                points
+            FROM
+                survivor_eliminations
+            WHERE
+                tournament_id = $1
+            ORDER BY
+                ordinal
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| SurvivorElimination {
+            tournament_user_id: row.get("tournament_user_id"),
+            ordinal: row.get("ordinal"),
+            points: row.get("points"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_is_eliminated(tournament_user_id: i64) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                survivor_eliminations
+            WHERE
+                tournament_user_id = $1
+            ",
+        )
+        .bind(tournament_user_id)
+        .fetch_optional(&pool)
+        .await?;
+
+        Ok(res.is_some())
+    }
+
+    pub async fn fetch_used_competitor_ids(
+        tournament_user_id: i64,
+        workout_id: i64,
+    ) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                competitor_id
+            FROM
+                tournament_user_picks
+            WHERE
+                tournament_user_id = $1
+                AND workout_id != $2
+            ",
+        )
+        .bind(tournament_user_id)
+        .bind(workout_id)
+        .map(|row: PgRow| row.get("competitor_id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn insert_survivor_elimination(
+        tournament_id: i64,
+        tournament_user_id: i64,
+        ordinal: i64,
+        points: Option<f64>,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO survivor_eliminations (tournament_id, tournament_user_id, ordinal, points, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .bind(ordinal)
+        .bind(points)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_survivor_eliminations(tournament_id: i64) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        let _ = sqlx::query(
+            "
+            DELETE FROM survivor_eliminations
+            WHERE tournament_id = $1
+            ",
+        )
+        .bind(tournament_id)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+}
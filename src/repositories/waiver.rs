@@ -0,0 +1,378 @@
+use crate::data::constants::{tournament_type, waiver_status};
+use crate::data::data_client::DataClient;
+use crate::data::models::waiver_claim::WaiverClaim;
+use crate::data::models::waiver_period::WaiverPeriod;
+use crate::handlers::draft::response_models::{WaiverClaimResponse, WaiverPriorityEntry};
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
+
+pub struct WaiverRepository;
+
+impl WaiverRepository {
+    fn map_period(row: PgRow) -> WaiverPeriod {
+        WaiverPeriod {
+            id: row.get("id"),
+            competition_id: row.get("competition_id"),
+            ordinal: row.get("ordinal"),
+            process_at: row.get("process_at"),
+            is_processed: row.get("is_processed"),
+        }
+    }
+
+    fn map_claim(row: PgRow) -> WaiverClaim {
+        WaiverClaim {
+            id: row.get("id"),
+            tournament_id: row.get("tournament_id"),
+            tournament_user_id: row.get("tournament_user_id"),
+            drop_competitor_id: row.get("drop_competitor_id"),
+            add_competitor_id: row.get("add_competitor_id"),
+            status: row.get("status"),
+            created_at: row.get("created_at"),
+        }
+    }
+
+    pub async fn fetch_open_waiver_period(
+        competition_id: i64,
+    ) -> Result<Option<WaiverPeriod>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                competition_id,
+                ordinal,
+                process_at,
+                is_processed
+            FROM
+                waiver_periods
+            WHERE
+                competition_id = $1
+                AND is_processed = false
+            ORDER BY
+                process_at
+            LIMIT 1
+            ",
+        )
+        .bind(competition_id)
+        .map(Self::map_period)
+        .fetch_optional(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_due_waiver_periods() -> Result<Vec<WaiverPeriod>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                competition_id,
+                ordinal,
+                process_at,
+                is_processed
+            FROM
+                waiver_periods
+            WHERE
+                is_processed = false
+                AND process_at < $1::timestamptz
+            ORDER BY
+                process_at
+            ",
+        )
+        .bind(format!("{}", chrono::Utc::now()))
+        .map(Self::map_period)
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waiver_tournament_ids(competition_id: i64) -> Result<Vec<i64>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id
+            FROM
+                tournament
+            WHERE
+                competition_id = $1
+                AND tournament_type_id IN ($2, $3)
+            ",
+        )
+        .bind(competition_id)
+        .bind(tournament_type::SNAKE_DRAFT)
+        .bind(tournament_type::AUCTION_DRAFT)
+        .map(|row: PgRow| row.get("id"))
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waiver_claim(claim_id: i64) -> Result<WaiverClaim, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                tournament_id,
+                tournament_user_id,
+                drop_competitor_id,
+                add_competitor_id,
+                status,
+                created_at
+            FROM
+                waiver_claims
+            WHERE
+                id = $1
+            ",
+        )
+        .bind(claim_id)
+        .map(Self::map_claim)
+        .fetch_one(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_pending_waiver_claims(
+        tournament_id: i64,
+    ) -> Result<Vec<WaiverClaim>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                id,
+                tournament_id,
+                tournament_user_id,
+                drop_competitor_id,
+                add_competitor_id,
+                status,
+                created_at
+            FROM
+                waiver_claims
+            WHERE
+                tournament_id = $1
+                AND status = $2
+            ORDER BY
+                id
+            ",
+        )
+        .bind(tournament_id)
+        .bind(waiver_status::PENDING)
+        .map(Self::map_claim)
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waiver_claims(
+        tournament_id: i64,
+    ) -> Result<Vec<WaiverClaimResponse>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                wc.id,
+                wc.tournament_user_id,
+                wc.drop_competitor_id,
+                wc.add_competitor_id,
+                wc.status,
+                wc.created_at,
+                CONCAT(dc.first_name, ' ', dc.last_name) as drop_name,
+                CONCAT(ac.first_name, ' ', ac.last_name) as add_name
+            FROM
+                waiver_claims wc
+            JOIN
+                competitor dc
+                ON dc.id = wc.drop_competitor_id
+            JOIN
+                competitor ac
+                ON ac.id = wc.add_competitor_id
+            WHERE
+                wc.tournament_id = $1
+            ORDER BY
+                wc.id DESC
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| WaiverClaimResponse {
+            claim_id: row.get("id"),
+            tournament_user_id: row.get("tournament_user_id"),
+            drop_competitor_id: row.get("drop_competitor_id"),
+            drop_name: row.get("drop_name"),
+            add_competitor_id: row.get("add_competitor_id"),
+            add_name: row.get("add_name"),
+            status: row.get("status"),
+            created_at: row.get("created_at"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn fetch_waiver_priority(
+        tournament_id: i64,
+    ) -> Result<Vec<WaiverPriorityEntry>, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            SELECT
+                wp.tournament_user_id,
+                wp.priority,
+                tu.display_name,
+                au.username,
+                au.profile_url
+            FROM
+                waiver_priority wp
+            JOIN
+                tournament_users tu
+                ON tu.id = wp.tournament_user_id
+            JOIN
+                app_user au
+                ON au.id = tu.user_id
+            WHERE
+                wp.tournament_id = $1
+            ORDER BY
+                wp.priority
+            ",
+        )
+        .bind(tournament_id)
+        .map(|row: PgRow| WaiverPriorityEntry {
+            tournament_user_id: row.get("tournament_user_id"),
+            display_name: row
+                .get::<Option<String>, _>("display_name")
+                .unwrap_or(row.get("username")),
+            avatar: row.get("profile_url"),
+            priority: row.get("priority"),
+        })
+        .fetch_all(&pool)
+        .await?;
+
+        Ok(res)
+    }
+
+    pub async fn insert_waiver_period(
+        competition_id: i64,
+        ordinal: i64,
+        process_at: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO waiver_periods (competition_id, ordinal, process_at, is_processed)
+            VALUES ($1, $2, $3::timestamptz, false)
+            ",
+        )
+        .bind(competition_id)
+        .bind(ordinal)
+        .bind(format!("{}", process_at))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_waiver_claim(
+        tournament_id: i64,
+        tournament_user_id: i64,
+        drop_competitor_id: i64,
+        add_competitor_id: i64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO waiver_claims (tournament_id, tournament_user_id, drop_competitor_id, add_competitor_id, status, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .bind(drop_competitor_id)
+        .bind(add_competitor_id)
+        .bind(waiver_status::PENDING)
+        .bind(format!("{}", chrono::Utc::now()))
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_waiver_priority(
+        tournament_id: i64,
+        tournament_user_id: i64,
+        priority: i64,
+    ) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            INSERT INTO waiver_priority (tournament_id, tournament_user_id, priority)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (tournament_id, tournament_user_id)
+            DO UPDATE SET priority = EXCLUDED.priority
+            ",
+        )
+        .bind(tournament_id)
+        .bind(tournament_user_id)
+        .bind(priority)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Only pending claims change, so a claim that's already settled keeps its outcome.
+    pub async fn update_waiver_claim_status(claim_id: i64, status: &str) -> Result<(), Error> {
+        let pool = DataClient::connect().await?;
+
+        sqlx::query(
+            "
+            UPDATE waiver_claims
+            SET status = $2
+            WHERE id = $1
+                AND status = $3
+            ",
+        )
+        .bind(claim_id)
+        .bind(status)
+        .bind(waiver_status::PENDING)
+        .execute(&pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Returns false when another request already processed the period.
+    pub async fn update_waiver_period_processed(period_id: i64) -> Result<bool, Error> {
+        let pool = DataClient::connect().await?;
+
+        let res = sqlx::query(
+            "
+            UPDATE waiver_periods
+            SET is_processed = true
+            WHERE id = $1
+                AND is_processed = false
+            ",
+        )
+        .bind(period_id)
+        .execute(&pool)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -14,7 +14,9 @@ pub struct AccountService;
 
 impl AccountService {
     pub async fn get_email_by_username(username: String) -> Result<String, Error> {
-        AppUserRepository::fetch_email_by_username(username).await
+        AppUserRepository::fetch_user_by_username(username)
+            .await
+            .map(|(_, email)| email)
     }
 
     pub async fn validate_new_username(username: String) -> Result<bool, Error> {
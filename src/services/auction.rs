@@ -0,0 +1,601 @@
+use crate::data::constants::{draft_status, tournament_type};
+use crate::data::models::auction::Auction;
+use crate::handlers::draft::request_models::{
+    BidRequest, NominationRequest, StartAuctionRequest, StartDraftPathRequest,
+};
+use crate::handlers::draft::response_models::{
+    AuctionLotResponse, AuctionMemberResponse, AuctionResponse, AuctionRosterEntry,
+    AuctionValueResponse, DraftOrderEntry, RosterEntry,
+};
+use crate::handlers::league::response_models::{
+    LeagueAthletesResponse, LeaguePosition, PickPercentage,
+};
+use crate::repositories::auction::AuctionRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::roster::RosterRepository;
+use chrono::{DateTime, Duration, Utc};
+use log::info;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub(crate) const DEFAULT_BUDGET: i64 = 200;
+pub(crate) const DEFAULT_BID_SECONDS: i64 = 30;
+
+struct AuctionBoard {
+    order: Vec<DraftOrderEntry>,
+    picks: Vec<AuctionRosterEntry>,
+    rosters: Vec<RosterEntry>,
+    positions: Vec<LeaguePosition>,
+    athletes: Vec<LeagueAthletesResponse>,
+    pick_percentages: HashMap<i64, Vec<PickPercentage>>,
+}
+
+pub struct AuctionService;
+
+impl AuctionService {
+    async fn load_board(auction: &Auction) -> Result<AuctionBoard, Error> {
+        Ok(AuctionBoard {
+            order: AuctionRepository::fetch_auction_order(auction.id).await?,
+            picks: AuctionRepository::fetch_auction_picks(auction.id).await?,
+            rosters: RosterRepository::fetch_rosters(auction.tournament_id).await?,
+            positions: LeagueRepository::fetch_positions(auction.tournament_id).await?,
+            athletes: LeagueRepository::fetch_league_athletes(auction.competition_id as u64)
+                .await?,
+            pick_percentages: LeagueRepository::fetch_pick_percentages(auction.competition_id)
+                .await?,
+        })
+    }
+
+    fn fits(position: &LeaguePosition, athlete_position_id: i64) -> bool {
+        match &position.allowed_positions {
+            Some(allowed) => allowed.contains(&athlete_position_id),
+            None => true,
+        }
+    }
+
+    // Prefers the most restrictive open slot so flex slots stay open for later wins.
+    fn open_slot(
+        board: &AuctionBoard,
+        tournament_user_id: i64,
+        athlete_position_id: i64,
+    ) -> Option<i64> {
+        board
+            .positions
+            .iter()
+            .filter(|p| {
+                !board.picks.iter().any(|r| {
+                    r.tournament_user_id == tournament_user_id
+                        && r.tournament_position_id == p.position_id
+                })
+            })
+            .filter(|p| Self::fits(p, athlete_position_id))
+            .min_by_key(|p| {
+                (
+                    p.allowed_positions
+                        .as_ref()
+                        .map(|a| a.len())
+                        .unwrap_or(usize::MAX),
+                    p.ordinal,
+                )
+            })
+            .map(|p| p.position_id)
+    }
+
+    fn roster(board: &AuctionBoard, tournament_user_id: i64) -> Vec<AuctionRosterEntry> {
+        board
+            .picks
+            .iter()
+            .filter(|r| r.tournament_user_id == tournament_user_id)
+            .cloned()
+            .collect()
+    }
+
+    fn open_slots(board: &AuctionBoard, tournament_user_id: i64) -> i64 {
+        board.positions.len() as i64 - Self::roster(board, tournament_user_id).len() as i64
+    }
+
+    fn spent(board: &AuctionBoard, tournament_user_id: i64) -> i64 {
+        Self::roster(board, tournament_user_id)
+            .iter()
+            .map(|r| r.price)
+            .sum()
+    }
+
+    // Every open slot after this one still needs at least one dollar.
+    fn max_bid(auction: &Auction, board: &AuctionBoard, tournament_user_id: i64) -> i64 {
+        let open_slots = Self::open_slots(board, tournament_user_id);
+
+        if open_slots == 0 {
+            return 0;
+        }
+
+        auction.budget - Self::spent(board, tournament_user_id) - (open_slots - 1)
+    }
+
+    // Rosters cover auction wins and any roster moves, so an athlete is only ever owned once.
+    fn available(board: &AuctionBoard) -> Vec<&LeagueAthletesResponse> {
+        board
+            .athletes
+            .iter()
+            .filter(|a| {
+                !a.is_withdrawn
+                    && !board
+                        .rosters
+                        .iter()
+                        .any(|r| r.competitor_id == a.competitor_id as i64)
+            })
+            .collect()
+    }
+
+    fn can_roster_any(board: &AuctionBoard, tournament_user_id: i64) -> bool {
+        Self::available(board)
+            .iter()
+            .any(|a| Self::open_slot(board, tournament_user_id, a.position_id).is_some())
+    }
+
+    fn nominator(board: &AuctionBoard, nomination_number: i64) -> Option<i64> {
+        let members = board.order.len() as i64;
+
+        (0..members)
+            .map(|k| (nomination_number - 1 + k) % members + 1)
+            .filter_map(|slot| board.order.iter().find(|o| o.slot == slot))
+            .map(|o| o.tournament_user_id)
+            .find(|tournament_user_id| Self::can_roster_any(board, *tournament_user_id))
+    }
+
+    // Splits the money left above the one dollar floor across the athletes expected to be bought,
+    // weighted by ADP and how often each athlete is picked across the competition.
+    fn suggested_values(auction: &Auction, board: &AuctionBoard) -> Vec<AuctionValueResponse> {
+        let mut values: Vec<(AuctionValueResponse, f64)> = Self::available(board)
+            .iter()
+            .map(|a| {
+                let percentages = board
+                    .pick_percentages
+                    .get(&(a.competitor_id as i64))
+                    .cloned()
+                    .unwrap_or_default();
+                let pick_percentage = if percentages.is_empty() {
+                    0.0
+                } else {
+                    percentages.iter().map(|p| p.percentage).sum::<f64>() / percentages.len() as f64
+                };
+                let weight = if a.adp > 0.0 {
+                    (1.0 / a.adp) * (1.0 + pick_percentage / 100.0)
+                } else {
+                    0.0
+                };
+
+                (
+                    AuctionValueResponse {
+                        competitor_id: a.competitor_id as i64,
+                        gender_id: a.gender_id as i64,
+                        first_name: a.first_name.clone(),
+                        last_name: a.last_name.clone(),
+                        position_id: a.position_id,
+                        adp: a.adp,
+                        pick_percentage,
+                        suggested_value: 1,
+                    },
+                    weight,
+                )
+            })
+            .collect();
+
+        values.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let open_slots: i64 = board
+            .order
+            .iter()
+            .map(|o| Self::open_slots(board, o.tournament_user_id))
+            .sum();
+        let surplus: i64 = board
+            .order
+            .iter()
+            .map(|o| {
+                auction.budget
+                    - Self::spent(board, o.tournament_user_id)
+                    - Self::open_slots(board, o.tournament_user_id)
+            })
+            .sum();
+        let weight_sum: f64 = values
+            .iter()
+            .take(open_slots as usize)
+            .map(|(_, w)| w)
+            .sum();
+
+        if weight_sum > 0.0 {
+            for (value, weight) in values.iter_mut().take(open_slots as usize) {
+                value.suggested_value += (surplus as f64 * *weight / weight_sum).floor() as i64;
+            }
+        }
+
+        values.into_iter().map(|(value, _)| value).collect()
+    }
+
+    fn is_expired(auction: &Auction) -> bool {
+        auction.status == draft_status::ACTIVE
+            && auction.bid_deadline.is_some_and(|d| d < Utc::now())
+    }
+
+    fn get_next_nomination(
+        auction: &Auction,
+        board: &AuctionBoard,
+    ) -> (&'static str, Option<DateTime<Utc>>) {
+        if Self::nominator(board, auction.nomination_number + 1).is_none() {
+            return (draft_status::COMPLETE, None);
+        }
+
+        (
+            draft_status::ACTIVE,
+            Some(Utc::now() + Duration::seconds(auction.bid_seconds)),
+        )
+    }
+
+    // Awards an open lot to the high bidder, or nominates for a member whose nomination clock ran out.
+    // The writes only apply while the nomination is still current, so when the close job and a
+    // write path race for the same lot, the later one finds it already handled.
+    async fn close_lot(auction: &Auction) -> Result<(), Error> {
+        let mut board = Self::load_board(auction).await?;
+
+        if let (Some(competitor_id), Some(high_bidder_id)) =
+            (auction.competitor_id, auction.high_bidder_id)
+        {
+            let athlete = board
+                .athletes
+                .iter()
+                .find(|a| a.competitor_id as i64 == competitor_id);
+            let slot = Self::open_slot(
+                &board,
+                high_bidder_id,
+                athlete.map(|a| a.position_id).unwrap_or_default(),
+            );
+
+            // The board was loaded before the award, so count it before finding the next nominator.
+            if let Some(slot) = slot {
+                board.picks.push(AuctionRosterEntry {
+                    tournament_user_id: high_bidder_id,
+                    tournament_position_id: slot,
+                    competitor_id,
+                    first_name: athlete.map(|a| a.first_name.clone()).unwrap_or_default(),
+                    last_name: athlete.map(|a| a.last_name.clone()).unwrap_or_default(),
+                    gender_id: athlete.map(|a| a.gender_id as i64).unwrap_or_default(),
+                    price: auction.current_bid,
+                });
+            }
+
+            let (status, bid_deadline) = Self::get_next_nomination(auction, &board);
+
+            AuctionRepository::award_auction_lot(auction, slot, status, bid_deadline).await?;
+
+            return Ok(());
+        }
+
+        let Some(nominator) = Self::nominator(&board, auction.nomination_number) else {
+            let (status, bid_deadline) = Self::get_next_nomination(auction, &board);
+
+            AuctionRepository::update_auction_nomination(
+                auction.id,
+                auction.nomination_number,
+                status,
+                bid_deadline,
+            )
+            .await?;
+
+            return Ok(());
+        };
+
+        let nomination = Self::suggested_values(auction, &board)
+            .into_iter()
+            .find(|v| Self::open_slot(&board, nominator, v.position_id).is_some());
+
+        info!(
+            "close_lot: auction {} nomination {} -> {:?}",
+            auction.id,
+            auction.nomination_number,
+            nomination.as_ref().map(|v| v.competitor_id)
+        );
+
+        match nomination {
+            Some(value) => {
+                AuctionRepository::update_auction_lot(
+                    auction.id,
+                    auction.nomination_number,
+                    value.competitor_id,
+                    nominator,
+                    1,
+                    Utc::now() + Duration::seconds(auction.bid_seconds),
+                )
+                .await?;
+            }
+            None => {
+                let (status, bid_deadline) = Self::get_next_nomination(auction, &board);
+
+                AuctionRepository::update_auction_nomination(
+                    auction.id,
+                    auction.nomination_number,
+                    status,
+                    bid_deadline,
+                )
+                .await?;
+            }
+        };
+
+        Ok(())
+    }
+
+    // Only write paths close an expired lot here, reads leave it to the auction close job.
+    async fn fetch_current_auction(tournament_id: i64) -> Result<Auction, Error> {
+        let auction = AuctionRepository::fetch_auction(tournament_id).await?;
+
+        if Self::is_expired(&auction) {
+            Self::close_lot(&auction).await?;
+
+            return AuctionRepository::fetch_auction(tournament_id).await;
+        }
+
+        Ok(auction)
+    }
+
+    pub async fn process_expired_auctions() -> Result<(), Error> {
+        let auctions = AuctionRepository::fetch_expired_auctions().await?;
+
+        for auction in auctions {
+            Self::close_lot(&auction).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_auction(tournament_id: i64) -> Result<AuctionResponse, Error> {
+        let auction = AuctionRepository::fetch_auction(tournament_id).await?;
+        let board = Self::load_board(&auction).await?;
+
+        let lot = auction.competitor_id.and_then(|competitor_id| {
+            board
+                .athletes
+                .iter()
+                .find(|a| a.competitor_id as i64 == competitor_id)
+                .map(|a| AuctionLotResponse {
+                    competitor_id,
+                    first_name: a.first_name.clone(),
+                    last_name: a.last_name.clone(),
+                    current_bid: auction.current_bid,
+                    high_bidder: auction.high_bidder_id,
+                })
+        });
+
+        let members = board
+            .order
+            .iter()
+            .map(|o| {
+                let spent = Self::spent(&board, o.tournament_user_id);
+
+                AuctionMemberResponse {
+                    tournament_user_id: o.tournament_user_id,
+                    display_name: o.display_name.clone(),
+                    avatar: o.avatar.clone(),
+                    slot: o.slot,
+                    spent,
+                    remaining: auction.budget - spent,
+                    max_bid: Self::max_bid(&auction, &board, o.tournament_user_id),
+                    open_slots: Self::open_slots(&board, o.tournament_user_id),
+                    roster: Self::roster(&board, o.tournament_user_id),
+                }
+            })
+            .collect();
+
+        Ok(AuctionResponse {
+            auction_id: auction.id,
+            tournament_id: auction.tournament_id,
+            nominator: if auction.status == draft_status::ACTIVE && lot.is_none() {
+                Self::nominator(&board, auction.nomination_number)
+            } else {
+                None
+            },
+            available: Self::suggested_values(&auction, &board),
+            status: auction.status,
+            budget: auction.budget,
+            bid_seconds: auction.bid_seconds,
+            nomination_number: auction.nomination_number,
+            deadline: auction.bid_deadline.map(|d| d.to_rfc3339()),
+            lot,
+            positions: board.positions,
+            members,
+        })
+    }
+
+    pub async fn start_auction(
+        path: &StartDraftPathRequest,
+        request: &StartAuctionRequest,
+    ) -> Result<AuctionResponse, String> {
+        let tournament = LeagueRepository::fetch_tournament(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to find league".to_string())?;
+
+        if tournament.commissioner_id as i64 != path.user_id {
+            return Err("Only the commissioner can start the auction".to_string());
+        }
+
+        if tournament.tournament_type_id as i64 != tournament_type::AUCTION_DRAFT {
+            return Err("League is not an auction league".to_string());
+        }
+
+        let auction = AuctionRepository::fetch_auction(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to find auction".to_string())?;
+
+        if auction.status != draft_status::PENDING {
+            return Err("Auction has already started".to_string());
+        }
+
+        let members = LeagueRepository::fetch_tournament_user_count(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to get league members".to_string())?;
+
+        if members < 2 {
+            return Err("An auction needs at least two members".to_string());
+        }
+
+        let budget = request.budget.unwrap_or(auction.budget);
+        let bid_seconds = request.bid_seconds.unwrap_or(auction.bid_seconds);
+
+        AuctionRepository::insert_auction_order(auction.id, path.tournament_id)
+            .await
+            .map_err(|_| "Unable to set nomination order".to_string())?;
+
+        AuctionRepository::update_auction_settings(
+            auction.id,
+            draft_status::ACTIVE,
+            budget,
+            bid_seconds,
+        )
+        .await
+        .map_err(|_| "Unable to start auction".to_string())?;
+
+        let is_started = AuctionRepository::update_auction_nomination(
+            auction.id,
+            auction.nomination_number,
+            draft_status::ACTIVE,
+            Some(Utc::now() + Duration::seconds(bid_seconds)),
+        )
+        .await
+        .map_err(|_| "Unable to start auction".to_string())?;
+
+        if !is_started {
+            return Err("Auction has already started".to_string());
+        }
+
+        Self::get_auction(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to get auction".to_string())
+    }
+
+    pub async fn nominate(
+        tournament_id: i64,
+        nomination: &NominationRequest,
+    ) -> Result<AuctionResponse, String> {
+        let auction = Self::fetch_current_auction(tournament_id)
+            .await
+            .map_err(|_| "Unable to find auction".to_string())?;
+
+        if auction.status != draft_status::ACTIVE {
+            return Err("Auction is not active".to_string());
+        }
+
+        if auction.competitor_id.is_some() {
+            return Err("A competitor is already up for bid".to_string());
+        }
+
+        let board = Self::load_board(&auction)
+            .await
+            .map_err(|_| "Unable to load auction".to_string())?;
+
+        if Self::nominator(&board, auction.nomination_number) != Some(nomination.tournament_user_id)
+        {
+            return Err("It is not your turn to nominate".to_string());
+        }
+
+        let Some(athlete) = Self::available(&board)
+            .into_iter()
+            .find(|a| a.competitor_id as i64 == nomination.competitor_id)
+        else {
+            return Err("Competitor is not available".to_string());
+        };
+
+        if Self::open_slot(&board, nomination.tournament_user_id, athlete.position_id).is_none() {
+            return Err("No open roster slot for this competitor".to_string());
+        }
+
+        if nomination.amount > Self::max_bid(&auction, &board, nomination.tournament_user_id) {
+            return Err("Bid exceeds your remaining budget".to_string());
+        }
+
+        let is_nominated = AuctionRepository::update_auction_lot(
+            auction.id,
+            auction.nomination_number,
+            nomination.competitor_id,
+            nomination.tournament_user_id,
+            nomination.amount,
+            Utc::now() + Duration::seconds(auction.bid_seconds),
+        )
+        .await
+        .map_err(|_| "Unable to save nomination".to_string())?;
+
+        if !is_nominated {
+            return Err("A competitor is already up for bid".to_string());
+        }
+
+        Self::get_auction(tournament_id)
+            .await
+            .map_err(|_| "Unable to get auction".to_string())
+    }
+
+    pub async fn place_bid(
+        tournament_id: i64,
+        bid: &BidRequest,
+    ) -> Result<AuctionResponse, String> {
+        let auction = Self::fetch_current_auction(tournament_id)
+            .await
+            .map_err(|_| "Unable to find auction".to_string())?;
+
+        if auction.status != draft_status::ACTIVE {
+            return Err("Auction is not active".to_string());
+        }
+
+        let Some(competitor_id) = auction.competitor_id else {
+            return Err("No competitor is up for bid".to_string());
+        };
+
+        if auction.high_bidder_id == Some(bid.tournament_user_id) {
+            return Err("You are already the high bidder".to_string());
+        }
+
+        if bid.amount <= auction.current_bid {
+            return Err("Bid must be higher than the current bid".to_string());
+        }
+
+        let board = Self::load_board(&auction)
+            .await
+            .map_err(|_| "Unable to load auction".to_string())?;
+
+        if !board
+            .order
+            .iter()
+            .any(|o| o.tournament_user_id == bid.tournament_user_id)
+        {
+            return Err("User is not a member of this auction".to_string());
+        }
+
+        let athlete_position_id = board
+            .athletes
+            .iter()
+            .find(|a| a.competitor_id as i64 == competitor_id)
+            .map(|a| a.position_id)
+            .unwrap_or_default();
+
+        if Self::open_slot(&board, bid.tournament_user_id, athlete_position_id).is_none() {
+            return Err("No open roster slot for this competitor".to_string());
+        }
+
+        if bid.amount > Self::max_bid(&auction, &board, bid.tournament_user_id) {
+            return Err("Bid exceeds your remaining budget".to_string());
+        }
+
+        let is_high_bid = AuctionRepository::update_auction_bid(
+            auction.id,
+            bid.tournament_user_id,
+            bid.amount,
+            Utc::now() + Duration::seconds(auction.bid_seconds),
+        )
+        .await
+        .map_err(|_| "Unable to save bid".to_string())?;
+
+        if !is_high_bid {
+            return Err("Bid was outbid or bidding has closed".to_string());
+        }
+
+        Self::get_auction(tournament_id)
+            .await
+            .map_err(|_| "Unable to get auction".to_string())
+    }
+}
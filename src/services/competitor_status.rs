@@ -0,0 +1,290 @@
+use crate::data::constants::{
+    athlete_status, league_activity, pick_action, pick_source, status_consequence, tournament_type,
+};
+use crate::handlers::competition::response_models::{
+    AffectedPickResponse, CompetitorStatusResponse,
+};
+use crate::handlers::league::response_models::{
+    AffectedPickData, CompetitorStatusChangeData, LeagueActivityData, LeagueAthletesResponse,
+    LeaguePosition, PickEventData, PickSlotData,
+};
+use crate::repositories::competitor_status::CompetitorStatusRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::services::draft::DraftService;
+use sqlx::Error;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+pub struct CompetitorStatusService;
+
+impl CompetitorStatusService {
+    // Returns (is_withdrawn, is_cut, is_suspended).
+    fn get_status_flags(status: &str) -> Option<(bool, bool, bool)> {
+        match status {
+            athlete_status::ACTIVE => Some((false, false, false)),
+            athlete_status::WITHDRAWN => Some((true, false, false)),
+            athlete_status::CUT => Some((false, true, false)),
+            athlete_status::SUSPENDED => Some((false, false, true)),
+            _ => None,
+        }
+    }
+
+    fn get_pick_slot(pick: &AffectedPickData) -> PickSlotData {
+        PickSlotData {
+            tournament_user_id: pick.tournament_user_id,
+            tournament_position_id: pick.tournament_position_id,
+            workout_id: pick.workout_id,
+            rank: pick.rank,
+        }
+    }
+
+    pub async fn is_free_swap(tournament_user_pick_id: Option<i64>) -> Result<bool, Error> {
+        match tournament_user_pick_id {
+            Some(pick_id) => CompetitorStatusRepository::fetch_is_free_swap(pick_id).await,
+            None => Ok(false),
+        }
+    }
+
+    fn get_pick_event(
+        action: &str,
+        pick: &AffectedPickData,
+        previous_competitor_id: i64,
+        competitor_id: Option<i64>,
+    ) -> PickEventData {
+        PickEventData {
+            action: action.to_string(),
+            source: pick_source::COMPETITOR_STATUS.to_string(),
+            slot: Self::get_pick_slot(pick),
+            previous_competitor_id: Some(previous_competitor_id),
+            competitor_id,
+        }
+    }
+
+    // Returning a competitor to active only clears the flags, consequences already applied
+    // to picks stay in place. The changes are worked out first and then written together, so
+    // a failure part way leaves the competitor and their picks as they were.
+    pub async fn update_competitor_status(
+        competition_id: i64,
+        competitor_id: i64,
+        status: &str,
+        consequence: Option<&str>,
+    ) -> Result<CompetitorStatusResponse, Error> {
+        let Some((is_withdrawn, is_cut, is_suspended)) = Self::get_status_flags(status) else {
+            return Err(Error::Protocol("Invalid competitor status".to_string()));
+        };
+
+        let consequence = match consequence {
+            _ if status == athlete_status::ACTIVE => None,
+            Some(
+                c @ (status_consequence::ZERO_POINTS
+                | status_consequence::FREE_SWAP
+                | status_consequence::AUTO_REPLACE),
+            ) => Some(c),
+            _ => return Err(Error::Protocol("Invalid status consequence".to_string())),
+        };
+
+        let mut change = CompetitorStatusChangeData {
+            competition_id,
+            competitor_id,
+            is_withdrawn,
+            is_cut,
+            is_suspended,
+            consequence: consequence.map(|c| c.to_string()),
+            invalidated_pick_ids: vec![],
+            replacements: vec![],
+            events: vec![],
+            activities: vec![],
+        };
+        let mut picks: Vec<AffectedPickData> = vec![];
+
+        if let Some(consequence) = consequence {
+            let athletes = LeagueRepository::fetch_league_athletes(competition_id as u64).await?;
+            picks = CompetitorStatusRepository::fetch_affected_picks(competition_id, competitor_id)
+                .await?;
+
+            match consequence {
+                status_consequence::ZERO_POINTS => Self::zero_points(&picks, &mut change),
+                status_consequence::AUTO_REPLACE => {
+                    Self::auto_replace(&athletes, &picks, &mut change).await?
+                }
+                _ => {}
+            };
+
+            let name = athletes
+                .iter()
+                .find(|a| a.competitor_id as i64 == competitor_id)
+                .map_or(String::new(), |a| {
+                    format!("{} {}", a.first_name, a.last_name)
+                });
+
+            Self::notify_members(&name, status, consequence, &picks, &mut change);
+        }
+
+        let updated = CompetitorStatusRepository::apply_competitor_status(&change).await?;
+
+        if updated == 0 {
+            return Err(Error::Protocol(
+                "Competitor isn't in this competition".to_string(),
+            ));
+        }
+
+        let affected_picks: Vec<AffectedPickResponse> = picks
+            .iter()
+            .map(|p| AffectedPickResponse {
+                tournament_id: p.tournament_id,
+                tournament_user_id: p.tournament_user_id,
+                tournament_user_pick_id: p.tournament_user_pick_id,
+                replacement_competitor_id: change
+                    .replacements
+                    .iter()
+                    .find(|(pick_id, _)| *pick_id == p.tournament_user_pick_id)
+                    .map(|(_, replacement_id)| *replacement_id),
+            })
+            .collect();
+
+        Ok(CompetitorStatusResponse {
+            competition_id,
+            competitor_id,
+            status: status.to_string(),
+            consequence: change.consequence,
+            affected_picks,
+        })
+    }
+
+    fn zero_points(picks: &[AffectedPickData], change: &mut CompetitorStatusChangeData) {
+        for pick in picks {
+            change
+                .invalidated_pick_ids
+                .push(pick.tournament_user_pick_id);
+            change.events.push(Self::get_pick_event(
+                pick_action::INVALIDATE,
+                pick,
+                change.competitor_id,
+                None,
+            ));
+        }
+    }
+
+    // Each pick goes to the best ADP athlete of the same gender who can fill the position and
+    // isn't already in the member's lineup (or that workout's picks for per-workout leagues).
+    // Draft and salary cap rosters and picks for workouts already underway are left as is.
+    async fn auto_replace(
+        athletes: &[LeagueAthletesResponse],
+        picks: &[AffectedPickData],
+        change: &mut CompetitorStatusChangeData,
+    ) -> Result<(), Error> {
+        let competitor_id = change.competitor_id;
+        let gender_id = LeagueRepository::fetch_competitor_gender_id(competitor_id).await?;
+
+        let mut candidates: Vec<&LeagueAthletesResponse> = athletes
+            .iter()
+            .filter(|a| {
+                a.gender_id as i64 == gender_id
+                    && a.competitor_id as i64 != competitor_id
+                    && !a.is_withdrawn
+                    && !a.is_cut
+                    && !a.is_suspended
+            })
+            .collect();
+        candidates.sort_by(|a, b| DraftService::adp_rank(a).total_cmp(&DraftService::adp_rank(b)));
+
+        let mut positions: HashMap<i64, Vec<LeaguePosition>> = HashMap::new();
+        // Replacements aren't written until the end, so earlier picks in this change count too.
+        let mut chosen: Vec<(i64, Option<i64>, i64)> = vec![];
+
+        for pick in picks {
+            let is_roster = pick.tournament_type_id == tournament_type::SNAKE_DRAFT
+                || pick.tournament_type_id == tournament_type::AUCTION_DRAFT
+                || pick.tournament_type_id == tournament_type::SALARY_CAP;
+
+            if is_roster || pick.is_workout_active {
+                continue;
+            }
+
+            if let Entry::Vacant(entry) = positions.entry(pick.tournament_id) {
+                entry.insert(LeagueRepository::fetch_positions(pick.tournament_id).await?);
+            }
+
+            let is_per_workout = pick.tournament_type_id == tournament_type::SHOTCALLER
+                || pick.tournament_type_id == tournament_type::PODIUM;
+            let workout_id = if is_per_workout {
+                pick.workout_id
+            } else {
+                None
+            };
+            let mut taken = CompetitorStatusRepository::fetch_member_competitor_ids(
+                pick.tournament_user_id,
+                workout_id,
+            )
+            .await?;
+            taken.extend(
+                chosen
+                    .iter()
+                    .filter(|(tournament_user_id, w, _)| {
+                        *tournament_user_id == pick.tournament_user_id && *w == workout_id
+                    })
+                    .map(|(_, _, c)| *c),
+            );
+            let position = positions[&pick.tournament_id]
+                .iter()
+                .find(|p| p.position_id == pick.tournament_position_id);
+
+            let Some(replacement) = candidates.iter().find(|a| {
+                !taken.contains(&(a.competitor_id as i64))
+                    && position
+                        .and_then(|p| p.allowed_positions.as_ref())
+                        .iter()
+                        .all(|allowed| allowed.contains(&a.position_id))
+            }) else {
+                continue;
+            };
+
+            let replacement_id = replacement.competitor_id as i64;
+
+            chosen.push((pick.tournament_user_id, workout_id, replacement_id));
+            change
+                .replacements
+                .push((pick.tournament_user_pick_id, replacement_id));
+            change.events.push(Self::get_pick_event(
+                pick_action::REPLACE,
+                pick,
+                competitor_id,
+                Some(replacement_id),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Each affected member gets one entry in their league's activity feed.
+    fn notify_members(
+        name: &str,
+        status: &str,
+        consequence: &str,
+        picks: &[AffectedPickData],
+        change: &mut CompetitorStatusChangeData,
+    ) {
+        let outcome = match consequence {
+            status_consequence::ZERO_POINTS => "picks score zero points",
+            status_consequence::FREE_SWAP => "a free swap is available",
+            _ => "picks were replaced",
+        };
+
+        for pick in picks {
+            if change
+                .activities
+                .iter()
+                .any(|a| a.tournament_id == pick.tournament_id && a.user_id == pick.user_id)
+            {
+                continue;
+            }
+
+            change.activities.push(LeagueActivityData {
+                tournament_id: pick.tournament_id,
+                user_id: pick.user_id,
+                action: league_activity::COMPETITOR_STATUS.to_string(),
+                detail: Some(format!("{} is {}, {}", name, status, outcome)),
+            });
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::data::constants::tournament_type;
+use crate::handlers::league::response_models::{
+    ConsensusPickData, LeaderboardMatchupResponse, LeaderboardPicks, LineupOverlapResponse,
+    LineupUniquenessEntry, MatchupDetail, MemberPickData,
+};
+use crate::repositories::consensus::ConsensusRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::services::league::LeagueService;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub struct ConsensusService;
+
+impl ConsensusService {
+    // The chalk lineup takes the most picked competitors, ordered by where entries ranked them.
+    fn get_consensus_picks(
+        consensus: &[ConsensusPickData],
+        pick_count: i64,
+    ) -> Vec<LeaderboardPicks> {
+        let mut picks: Vec<&ConsensusPickData> =
+            consensus.iter().take(pick_count as usize).collect();
+
+        picks.sort_by(|a, b| a.average_rank.total_cmp(&b.average_rank));
+
+        picks
+            .iter()
+            .enumerate()
+            .map(|(i, p)| LeaderboardPicks {
+                competitor_id: p.competitor_id,
+                rank: i as i64 + 1,
+                is_captain: false,
+            })
+            .collect()
+    }
+
+    pub async fn get_consensus_matchup(
+        tournament_id: i64,
+        user_id: i64,
+    ) -> Result<LeaderboardMatchupResponse, Error> {
+        let (metadata, men_leaderboard, women_leaderboard) =
+            LeagueService::fetch_matchup_leaderboards(tournament_id).await?;
+
+        if metadata.tournament_type_id as i64 != tournament_type::TOP_N {
+            return Err(Error::Protocol(
+                "Consensus lineups are only available for Top-N leagues".to_string(),
+            ));
+        }
+
+        let competition_id = metadata.competition_id as i64;
+        let men_consensus = ConsensusRepository::fetch_top_n_consensus(competition_id, 1).await?;
+        let women_consensus = ConsensusRepository::fetch_top_n_consensus(competition_id, 2).await?;
+
+        let mut tournament_users =
+            LeagueRepository::fetch_matchup_users(tournament_id, user_id, 0).await?;
+        let Some(user_picks) = tournament_users
+            .iter_mut()
+            .find(|tu| tu.tournament_user_id as i64 == user_id)
+        else {
+            return Err(Error::Protocol("League member not found".to_string()));
+        };
+
+        // Like any matchup, the member's lineup stays hidden until the competition starts.
+        if !LeagueService::is_competition_locked(tournament_id).await? {
+            LeagueService::hide_matchup_picks(user_picks);
+        }
+
+        let user_men_players = LeagueService::get_matchup_picks(
+            user_picks.men_competitor_ids.clone(),
+            &men_leaderboard,
+        );
+        let user_women_players = LeagueService::get_matchup_picks(
+            user_picks.women_competitor_ids.clone(),
+            &women_leaderboard,
+        );
+        let consensus_men_players = LeagueService::get_matchup_picks(
+            Self::get_consensus_picks(&men_consensus, metadata.pick_count),
+            &men_leaderboard,
+        );
+        let consensus_women_players = LeagueService::get_matchup_picks(
+            Self::get_consensus_picks(&women_consensus, metadata.pick_count),
+            &women_leaderboard,
+        );
+
+        Ok(LeaderboardMatchupResponse {
+            locked_events: metadata.locked_events,
+            user_matchup: MatchupDetail {
+                men_points: user_men_players.iter().map(|p| p.points).sum(),
+                women_points: user_women_players.iter().map(|p| p.points).sum(),
+                men_players: user_men_players,
+                women_players: user_women_players,
+            },
+            competitor_matchup: MatchupDetail {
+                men_points: consensus_men_players.iter().map(|p| p.points).sum(),
+                women_points: consensus_women_players.iter().map(|p| p.points).sum(),
+                men_players: consensus_men_players,
+                women_players: consensus_women_players,
+            },
+        })
+    }
+
+    // Uniqueness is the average share of the field that passed on each of the lineup's picks,
+    // so a lineup nobody else would pick scores close to 100.
+    pub async fn get_uniqueness(tournament_id: i64) -> Result<Vec<LineupUniquenessEntry>, Error> {
+        let metadata = LeagueRepository::fetch_competition(tournament_id).await?;
+        let competition_id = metadata.competition_id as i64;
+
+        let pick_percentages: HashMap<(i64, Option<i64>), f64> = if metadata.tournament_type_id
+            as i64
+            == tournament_type::TOP_N
+        {
+            let mut consensus =
+                ConsensusRepository::fetch_top_n_consensus(competition_id, 1).await?;
+            consensus.extend(ConsensusRepository::fetch_top_n_consensus(competition_id, 2).await?);
+
+            consensus
+                .into_iter()
+                .map(|c| ((c.competitor_id, None), c.pick_percentage))
+                .collect()
+        } else if metadata.tournament_type_id as i64 == tournament_type::SHOTCALLER {
+            LeagueRepository::fetch_pick_percentages(competition_id)
+                .await?
+                .into_iter()
+                .flat_map(|(competitor_id, percentages)| {
+                    percentages
+                        .into_iter()
+                        .map(move |p| ((competitor_id, Some(p.workout_id)), p.percentage))
+                })
+                .collect()
+        } else {
+            return Err(Error::Protocol(
+                "Lineup uniqueness is only available for Top-N and ShotCaller leagues".to_string(),
+            ));
+        };
+
+        let members = LeagueRepository::fetch_tournament_users(tournament_id).await?;
+        let picks = ConsensusRepository::fetch_member_picks(tournament_id).await?;
+
+        let mut uniqueness: Vec<LineupUniquenessEntry> = members
+            .into_iter()
+            .map(|m| {
+                let member_picks: Vec<f64> = picks
+                    .iter()
+                    .filter(|p| p.tournament_user_id == m.tournament_user_id as i64)
+                    .map(|p| {
+                        100.0
+                            - pick_percentages
+                                .get(&(p.competitor_id, p.workout_id))
+                                .copied()
+                                .unwrap_or(0.0)
+                    })
+                    .collect();
+
+                LineupUniquenessEntry {
+                    tournament_user_id: m.tournament_user_id as i64,
+                    display_name: m.display_name,
+                    avatar: m.avatar,
+                    picks: member_picks.len() as i64,
+                    uniqueness: if member_picks.is_empty() {
+                        0.0
+                    } else {
+                        member_picks.iter().sum::<f64>() / member_picks.len() as f64
+                    },
+                }
+            })
+            .collect();
+
+        uniqueness.sort_by(|a, b| b.uniqueness.total_cmp(&a.uniqueness));
+
+        Ok(uniqueness)
+    }
+
+    // ShotCaller picks only match when they're for the same workout. Like matchups, only
+    // picks that have locked are compared: Top-N once the competition starts and ShotCaller
+    // once the pick's workout is active.
+    pub async fn get_overlap(
+        tournament_id: i64,
+        user_id: i64,
+        competitor_id: i64,
+    ) -> Result<LineupOverlapResponse, Error> {
+        let is_locked = LeagueService::is_competition_locked(tournament_id).await?;
+        let locked_workout_ids: Vec<i64> =
+            LeagueRepository::fetch_workouts_by_tournament(tournament_id)
+                .await?
+                .into_iter()
+                .filter(|w| w.is_active || w.is_complete)
+                .map(|w| w.id)
+                .collect();
+
+        let picks: Vec<MemberPickData> = ConsensusRepository::fetch_member_picks(tournament_id)
+            .await?
+            .into_iter()
+            .filter(|p| match p.workout_id {
+                Some(workout_id) => locked_workout_ids.contains(&workout_id),
+                None => is_locked,
+            })
+            .collect();
+
+        let user_picks: Vec<_> = picks
+            .iter()
+            .filter(|p| p.tournament_user_id == user_id)
+            .collect();
+        let shared_competitor_ids: Vec<i64> = user_picks
+            .iter()
+            .filter(|u| {
+                picks.iter().any(|p| {
+                    p.tournament_user_id == competitor_id
+                        && p.competitor_id == u.competitor_id
+                        && p.workout_id == u.workout_id
+                })
+            })
+            .map(|u| u.competitor_id)
+            .collect();
+
+        Ok(LineupOverlapResponse {
+            user_id,
+            competitor_id,
+            shared_picks: shared_competitor_ids.len() as i64,
+            total_picks: user_picks.len() as i64,
+            shared_competitor_ids,
+        })
+    }
+}
@@ -0,0 +1,297 @@
+use crate::data::constants::{draft_status, tournament_type};
+use crate::data::models::draft::Draft;
+use crate::handlers::draft::request_models::{
+    DraftPickRequest, StartDraftPathRequest, StartDraftRequest,
+};
+use crate::handlers::draft::response_models::{DraftOrderEntry, DraftResponse};
+use crate::handlers::league::response_models::LeagueAthletesResponse;
+use crate::repositories::draft::DraftRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::roster::RosterRepository;
+use chrono::{Duration, Utc};
+use log::info;
+use sqlx::Error;
+
+pub(crate) const DEFAULT_PICK_SECONDS: i64 = 90;
+
+pub struct DraftService;
+
+impl DraftService {
+    // Returns the round and the 1-based draft slot for a pick, reversing the order every other round.
+    fn snake_slot(pick_number: i64, members: i64) -> (i64, i64) {
+        let round = (pick_number - 1) / members + 1;
+        let index = (pick_number - 1) % members;
+
+        if round % 2 == 0 {
+            (round, members - index)
+        } else {
+            (round, index + 1)
+        }
+    }
+
+    fn on_the_clock(draft: &Draft, order: &[DraftOrderEntry]) -> Option<i64> {
+        if draft.status != draft_status::ACTIVE || order.is_empty() {
+            return None;
+        }
+
+        let (_, slot) = Self::snake_slot(draft.current_pick, order.len() as i64);
+
+        order
+            .iter()
+            .find(|o| o.slot == slot)
+            .map(|o| o.tournament_user_id)
+    }
+
+    fn is_expired(draft: &Draft) -> bool {
+        draft.status == draft_status::ACTIVE && draft.pick_deadline.is_some_and(|d| d < Utc::now())
+    }
+
+    // Competitors without an ADP yet are drafted last.
+    pub(crate) fn adp_rank(athlete: &LeagueAthletesResponse) -> f64 {
+        if athlete.adp > 0.0 {
+            athlete.adp
+        } else {
+            f64::MAX
+        }
+    }
+
+    // Rosters cover draft picks and any roster moves, so an athlete is only ever owned once.
+    async fn fetch_available_athletes(draft: &Draft) -> Result<Vec<LeagueAthletesResponse>, Error> {
+        let athletes = LeagueRepository::fetch_league_athletes(draft.competition_id as u64).await?;
+        let rosters = RosterRepository::fetch_rosters(draft.tournament_id).await?;
+
+        Ok(athletes
+            .into_iter()
+            .filter(|a| {
+                !a.is_withdrawn
+                    && !rosters
+                        .iter()
+                        .any(|r| r.competitor_id == a.competitor_id as i64)
+            })
+            .collect())
+    }
+
+    // Returns false when the pick was already made by a racing request.
+    async fn record_pick(
+        draft: &Draft,
+        members: i64,
+        tournament_user_id: i64,
+        competitor_id: i64,
+        is_auto_pick: bool,
+    ) -> Result<bool, Error> {
+        let (round, _) = Self::snake_slot(draft.current_pick, members);
+        let is_complete = draft.current_pick + 1 > draft.rounds * members;
+
+        let (status, pick_deadline) = if is_complete {
+            (draft_status::COMPLETE, None)
+        } else {
+            (
+                draft_status::ACTIVE,
+                Some(Utc::now() + Duration::seconds(draft.pick_seconds)),
+            )
+        };
+
+        DraftRepository::record_draft_pick(
+            draft,
+            round,
+            tournament_user_id,
+            competitor_id,
+            is_auto_pick,
+            status,
+            pick_deadline,
+        )
+        .await
+    }
+
+    async fn auto_pick(draft: &Draft) -> Result<(), Error> {
+        let order = DraftRepository::fetch_draft_order(draft.id).await?;
+
+        let Some(tournament_user_id) = Self::on_the_clock(draft, &order) else {
+            return Ok(());
+        };
+
+        let available = Self::fetch_available_athletes(draft).await?;
+        let best = available
+            .iter()
+            .min_by(|a, b| Self::adp_rank(a).total_cmp(&Self::adp_rank(b)));
+
+        info!(
+            "auto_pick: draft {} pick {} -> {:?}",
+            draft.id,
+            draft.current_pick,
+            best.map(|a| a.competitor_id)
+        );
+
+        match best {
+            Some(athlete) => {
+                Self::record_pick(
+                    draft,
+                    order.len() as i64,
+                    tournament_user_id,
+                    athlete.competitor_id as i64,
+                    true,
+                )
+                .await?;
+
+                Ok(())
+            }
+            None => {
+                DraftRepository::update_draft_clock(
+                    draft.id,
+                    draft_status::COMPLETE,
+                    draft.pick_seconds,
+                    draft.current_pick,
+                    None,
+                )
+                .await
+            }
+        }
+    }
+
+    // Only write paths catch up an expired clock here, reads leave it to the autopick job.
+    async fn fetch_current_draft(tournament_id: i64) -> Result<Draft, Error> {
+        let draft = DraftRepository::fetch_draft(tournament_id).await?;
+
+        if Self::is_expired(&draft) {
+            Self::auto_pick(&draft).await?;
+
+            return DraftRepository::fetch_draft(tournament_id).await;
+        }
+
+        Ok(draft)
+    }
+
+    pub async fn process_expired_drafts() -> Result<(), Error> {
+        let drafts = DraftRepository::fetch_expired_drafts().await?;
+
+        for draft in drafts {
+            Self::auto_pick(&draft).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_draft(tournament_id: i64) -> Result<DraftResponse, Error> {
+        let draft = DraftRepository::fetch_draft(tournament_id).await?;
+        let order = DraftRepository::fetch_draft_order(draft.id).await?;
+        let picks = DraftRepository::fetch_draft_picks(draft.id).await?;
+        let available = Self::fetch_available_athletes(&draft).await?;
+
+        Ok(DraftResponse {
+            draft_id: draft.id,
+            tournament_id: draft.tournament_id,
+            on_the_clock: Self::on_the_clock(&draft, &order),
+            status: draft.status,
+            pick_seconds: draft.pick_seconds,
+            rounds: draft.rounds,
+            current_pick: draft.current_pick,
+            pick_deadline: draft.pick_deadline.map(|d| d.to_rfc3339()),
+            order,
+            picks,
+            available,
+        })
+    }
+
+    pub async fn start_draft(
+        path: &StartDraftPathRequest,
+        request: &StartDraftRequest,
+    ) -> Result<DraftResponse, String> {
+        let tournament = LeagueRepository::fetch_tournament(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to find league".to_string())?;
+
+        if tournament.commissioner_id as i64 != path.user_id {
+            return Err("Only the commissioner can start the draft".to_string());
+        }
+
+        if tournament.tournament_type_id as i64 != tournament_type::SNAKE_DRAFT {
+            return Err("League is not a draft league".to_string());
+        }
+
+        let draft = DraftRepository::fetch_draft(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to find draft".to_string())?;
+
+        if draft.status != draft_status::PENDING {
+            return Err("Draft has already started".to_string());
+        }
+
+        let members = LeagueRepository::fetch_tournament_user_count(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to get league members".to_string())?;
+
+        if members < 2 {
+            return Err("A draft needs at least two members".to_string());
+        }
+
+        let pick_seconds = request.pick_seconds.unwrap_or(draft.pick_seconds);
+
+        DraftRepository::insert_draft_order(draft.id, path.tournament_id)
+            .await
+            .map_err(|_| "Unable to set draft order".to_string())?;
+
+        DraftRepository::update_draft_clock(
+            draft.id,
+            draft_status::ACTIVE,
+            pick_seconds,
+            1,
+            Some(Utc::now() + Duration::seconds(pick_seconds)),
+        )
+        .await
+        .map_err(|_| "Unable to start draft".to_string())?;
+
+        Self::get_draft(path.tournament_id)
+            .await
+            .map_err(|_| "Unable to get draft".to_string())
+    }
+
+    pub async fn make_pick(
+        tournament_id: i64,
+        pick: &DraftPickRequest,
+    ) -> Result<DraftResponse, String> {
+        let draft = Self::fetch_current_draft(tournament_id)
+            .await
+            .map_err(|_| "Unable to find draft".to_string())?;
+
+        if draft.status != draft_status::ACTIVE {
+            return Err("Draft is not active".to_string());
+        }
+
+        let order = DraftRepository::fetch_draft_order(draft.id)
+            .await
+            .map_err(|_| "Unable to get draft order".to_string())?;
+
+        if Self::on_the_clock(&draft, &order) != Some(pick.tournament_user_id) {
+            return Err("It is not your turn to pick".to_string());
+        }
+
+        let available = Self::fetch_available_athletes(&draft)
+            .await
+            .map_err(|_| "Unable to get available athletes".to_string())?;
+
+        if !available
+            .iter()
+            .any(|a| a.competitor_id as i64 == pick.competitor_id)
+        {
+            return Err("Competitor is not available".to_string());
+        }
+
+        let is_recorded = Self::record_pick(
+            &draft,
+            order.len() as i64,
+            pick.tournament_user_id,
+            pick.competitor_id,
+            false,
+        )
+        .await
+        .map_err(|_| "Unable to save draft pick".to_string())?;
+
+        if !is_recorded {
+            return Err("It is not your turn to pick".to_string());
+        }
+
+        Self::get_draft(tournament_id)
+            .await
+            .map_err(|_| "Unable to get draft".to_string())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::data::constants::tournament_type;
+use crate::handlers::league::request_models::GlobalLeaderboardRequest;
+use crate::handlers::league::response_models::GlobalLeaderboardResponse;
+use crate::repositories::global_leaderboard::GlobalLeaderboardRepository;
+use sqlx::Error;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const DEFAULT_WINDOW: i64 = 5;
+
+pub struct GlobalLeaderboardService;
+
+impl GlobalLeaderboardService {
+    pub async fn get_global_leaderboard(
+        req: &GlobalLeaderboardRequest,
+    ) -> Result<GlobalLeaderboardResponse, Error> {
+        if req.tournament_type_id != tournament_type::TOP_N
+            && req.tournament_type_id != tournament_type::SHOTCALLER
+        {
+            return Err(Error::Protocol(
+                "Global leaderboards are only available for Top-N and ShotCaller leagues"
+                    .to_string(),
+            ));
+        }
+
+        let page = req.page.unwrap_or(1);
+        let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        let window = req.window.unwrap_or(DEFAULT_WINDOW);
+
+        let total = GlobalLeaderboardRepository::fetch_global_leaderboard_count(
+            req.competition_id,
+            req.tournament_type_id,
+        )
+        .await?;
+
+        let leaderboard = GlobalLeaderboardRepository::fetch_global_leaderboard(
+            req.competition_id,
+            req.tournament_type_id,
+            None,
+            (page - 1) * page_size,
+            page_size,
+        )
+        .await?;
+
+        let user = match req.tournament_user_id {
+            Some(tournament_user_id) => GlobalLeaderboardRepository::fetch_global_leaderboard(
+                req.competition_id,
+                req.tournament_type_id,
+                Some(tournament_user_id),
+                0,
+                1,
+            )
+            .await?
+            .pop(),
+            None => None,
+        };
+
+        // The window is built from leaderboard positions so ties don't shift it.
+        let around_user = match &user {
+            Some(entry) => {
+                GlobalLeaderboardRepository::fetch_global_leaderboard(
+                    req.competition_id,
+                    req.tournament_type_id,
+                    None,
+                    (entry.position - window - 1).max(0),
+                    window * 2 + 1,
+                )
+                .await?
+            }
+            None => vec![],
+        };
+
+        Ok(GlobalLeaderboardResponse {
+            competition_id: req.competition_id,
+            tournament_type_id: req.tournament_type_id,
+            total,
+            page,
+            page_size,
+            leaderboard,
+            user,
+            around_user,
+        })
+    }
+}
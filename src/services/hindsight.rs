@@ -0,0 +1,162 @@
+use crate::data::constants::{captain, tournament_type};
+use crate::handlers::league::response_models::{
+    CompetitionLeaderboardResponse, HindsightMember, HindsightPick, HindsightResponse,
+    LeaguePosition,
+};
+use crate::repositories::hindsight::HindsightRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::services::league::LeagueService;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub struct HindsightService;
+
+impl HindsightService {
+    // Matching the best finishers to the ranks in order minimises the total rank difference,
+    // so the perfect Top-N entry is simply each gender's top finishers.
+    fn get_top_n_lineup(
+        pick_count: i64,
+        leaderboards: &[&HashMap<i64, CompetitionLeaderboardResponse>],
+    ) -> Vec<HindsightPick> {
+        let mut lineup: Vec<HindsightPick> = vec![];
+
+        for leaderboard in leaderboards {
+            let mut finishers: Vec<&CompetitionLeaderboardResponse> =
+                leaderboard.values().filter(|c| c.placement > 0).collect();
+
+            finishers.sort_by_key(|c| (c.placement, c.competitor_id));
+
+            lineup.extend(
+                finishers
+                    .iter()
+                    .take(pick_count as usize)
+                    .enumerate()
+                    .map(|(i, c)| HindsightPick {
+                        competitor_id: c.competitor_id,
+                        first_name: c.first_name.clone(),
+                        last_name: c.last_name.clone(),
+                        gender_id: Some(c.gender_id),
+                        rank: Some(i as i64 + 1),
+                        workout_id: None,
+                        tournament_position_id: None,
+                        points: (10 - (i as i64 + 1 - c.placement).abs()).max(0) as f64,
+                        is_captain: false,
+                    }),
+            );
+        }
+
+        lineup
+    }
+
+    // Fills the most restrictive positions first so flex spots don't take an athlete a
+    // specific position needs.
+    async fn get_shotcaller_lineup(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<Vec<HindsightPick>, Error> {
+        let workouts = LeagueRepository::fetch_workouts_by_tournament(tournament_id).await?;
+        let scores = HindsightRepository::fetch_workout_scores(competition_id).await?;
+        let mut positions: Vec<LeaguePosition> =
+            LeagueRepository::fetch_positions(tournament_id).await?;
+
+        positions.sort_by_key(|p| {
+            p.allowed_positions
+                .as_ref()
+                .map_or(usize::MAX, |allowed| allowed.len())
+        });
+
+        let mut lineup: Vec<HindsightPick> = vec![];
+
+        for workout in &workouts {
+            let mut workout_picks: Vec<HindsightPick> = vec![];
+
+            for position in &positions {
+                let best = scores.iter().find(|s| {
+                    s.ordinal == workout.ordinal
+                        && !workout_picks
+                            .iter()
+                            .any(|p| p.competitor_id == s.competitor_id)
+                        && position
+                            .allowed_positions
+                            .iter()
+                            .all(|allowed| allowed.contains(&s.position_id.unwrap_or(0)))
+                });
+
+                if let Some(best) = best {
+                    workout_picks.push(HindsightPick {
+                        competitor_id: best.competitor_id,
+                        first_name: best.first_name.clone(),
+                        last_name: best.last_name.clone(),
+                        gender_id: None,
+                        rank: None,
+                        workout_id: Some(workout.id),
+                        tournament_position_id: Some(position.position_id),
+                        points: best.points,
+                        is_captain: false,
+                    });
+                }
+            }
+
+            Self::apply_captain(&mut workout_picks);
+            lineup.extend(workout_picks);
+        }
+
+        Ok(lineup)
+    }
+
+    fn apply_captain(picks: &mut [HindsightPick]) {
+        if let Some(best) = picks
+            .iter_mut()
+            .max_by(|a, b| a.points.total_cmp(&b.points))
+        {
+            best.points *= captain::MULTIPLIER;
+            best.is_captain = true;
+        }
+    }
+
+    pub async fn get_hindsight_lineup(tournament_id: i64) -> Result<HindsightResponse, Error> {
+        let (metadata, men_leaderboard, women_leaderboard) =
+            LeagueService::fetch_matchup_leaderboards(tournament_id).await?;
+
+        let optimal_lineup = if metadata.tournament_type_id as i64 == tournament_type::TOP_N {
+            let mut lineup = Self::get_top_n_lineup(
+                metadata.pick_count,
+                &[&men_leaderboard, &women_leaderboard],
+            );
+
+            // Top-N leagues get a single captain across the whole lineup.
+            Self::apply_captain(&mut lineup);
+            lineup
+        } else if metadata.tournament_type_id as i64 == tournament_type::SHOTCALLER {
+            Self::get_shotcaller_lineup(tournament_id, metadata.competition_id as i64).await?
+        } else {
+            return Err(Error::Protocol(
+                "Hindsight lineups are only available for Top-N and ShotCaller leagues".to_string(),
+            ));
+        };
+
+        let optimal_points: f64 = optimal_lineup.iter().map(|p| p.points).sum();
+        let leaderboard = LeagueService::get_league_leaderboard_new(&tournament_id).await?;
+
+        let mut members: Vec<HindsightMember> = leaderboard
+            .leaderboard
+            .into_iter()
+            .map(|entry| HindsightMember {
+                tournament_user_id: entry.tournament_user_id,
+                display_name: entry.display_name,
+                avatar: entry.avatar,
+                points: entry.points,
+                points_left: (optimal_points - entry.points).max(0.0),
+            })
+            .collect();
+
+        members.sort_by(|a, b| a.points_left.total_cmp(&b.points_left));
+
+        Ok(HindsightResponse {
+            tournament_id,
+            optimal_points,
+            optimal_lineup,
+            members,
+        })
+    }
+}
@@ -1,11 +1,41 @@
-use crate::handlers::league::request_models::{CreateTopPickRequest, SwapPickRequest};
+use crate::data::models::tournament_invitation::TournamentInvitation;
+use crate::handlers::league::request_models::{
+    CommissionerMemberRequest, CommissionerRequest, CreateConfidencePickRequest,
+    CreateInvitationRequest, CreateTopPickRequest, MultiMatchupRequest, SetCaptainRequest,
+    SwapPickRequest,
+};
 use crate::handlers::league::response_models::{
-    LeaderboardMatchupShotcallerResponse, LeaderboardShotcallerPicks, MatchupShotcallerDetail,
-    MatchupShotcallerPick, PositionPicks, ShotCallerPicksBetaResponse,
-    UserLeaguesTopPicksDataResponse,
+    LeaderboardEvent, LeaderboardMatchupShotcallerResponse, LeaderboardMetadataData,
+    LeaderboardShotcallerPicks, LeagueActivityData, LeagueActivityResponse,
+    LeagueInvitationResponse, MatchupShotcallerDetail, MatchupShotcallerPick, MultiMatchupMember,
+    MultiMatchupPick, MultiMatchupResponse, MultiMatchupSlot, PickSlotData, PositionPicks,
+    ShotCallerPicksBetaResponse, TournamentMemberData, UserLeaguesTopPicksDataResponse,
 };
+use crate::repositories::app_user::AppUserRepository;
+use crate::repositories::auction::AuctionRepository;
+use crate::repositories::confidence::ConfidenceRepository;
+use crate::repositories::draft::DraftRepository;
+use crate::repositories::pick_event::PickEventRepository;
+use crate::repositories::podium::PodiumRepository;
+use crate::repositories::roster::RosterRepository;
+use crate::repositories::salary::SalaryRepository;
+use crate::services::auction::{DEFAULT_BID_SECONDS, DEFAULT_BUDGET};
+use crate::services::competitor_status::CompetitorStatusService;
+use crate::services::draft::DEFAULT_PICK_SECONDS;
+use crate::services::pick_event::PickEventService;
+use crate::services::pick_validation::PickValidationService;
+use crate::services::podium::PodiumService;
+use crate::services::snapshot::SnapshotService;
+use crate::services::survivor::SurvivorService;
+use crate::services::waiver::WaiverService;
 use crate::{
-    data::{constants::ntfy, models::tournament::Tournament},
+    data::{
+        constants::{
+            captain, invitation_status, league_activity, ntfy, pick_action, pick_source,
+            snapshot_source, tournament_type,
+        },
+        models::tournament::Tournament,
+    },
     handlers::{
         league::{
             request_models::{
@@ -30,9 +60,22 @@ use crate::{
 use log::info;
 use sqlx::testing::TestTermination;
 use sqlx::Error;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use tokio::join;
 
+// Only the commissioner can delete a league, so that refusal is kept apart from data errors.
+#[derive(Debug)]
+pub enum DeleteTournamentError {
+    NotCommissioner,
+    Data(Error),
+}
+
+impl From<Error> for DeleteTournamentError {
+    fn from(e: Error) -> Self {
+        DeleteTournamentError::Data(e)
+    }
+}
+
 pub struct LeagueService;
 
 impl LeagueService {
@@ -55,7 +98,150 @@ impl LeagueService {
     pub async fn get_user_leagues(
         user_league: &UserLeaguesRequest,
     ) -> Result<Vec<UserLeaguesResponse>, Error> {
-        LeagueRepository::fetch_user_leagues(user_league).await
+        let mut user_leagues = LeagueRepository::fetch_user_leagues(user_league).await?;
+        let invitation_leagues =
+            LeagueRepository::fetch_invitation_leagues(user_league.user_id).await?;
+        let waitlist_leagues =
+            LeagueRepository::fetch_waitlist_leagues(user_league.user_id).await?;
+
+        user_leagues.extend(invitation_leagues);
+        user_leagues.extend(waitlist_leagues);
+
+        Ok(user_leagues)
+    }
+
+    pub async fn get_invitations(user_id: i64) -> Result<Vec<LeagueInvitationResponse>, Error> {
+        LeagueRepository::fetch_user_invitations(user_id).await
+    }
+
+    pub async fn send_invitation(invitation: &CreateInvitationRequest) -> Result<(), String> {
+        let commissioner_id =
+            LeagueRepository::fetch_tournament_commissioner_id(invitation.tournament_id)
+                .await
+                .map_err(|_| "Unable to find league".to_string())?;
+
+        if commissioner_id != invitation.user_id {
+            return Err("Only the commissioner can invite users".to_string());
+        }
+
+        let (invitee_id, _) =
+            AppUserRepository::fetch_user_by_username(invitation.username.clone())
+                .await
+                .map_err(|_| "No user found with that username".to_string())?;
+
+        if invitee_id == invitation.user_id {
+            return Err("Can't invite yourself to a league".to_string());
+        }
+
+        let is_user_in_league = LeagueRepository::fetch_is_user_in_league(&JoinLeague {
+            user_id: invitee_id,
+            tournament_id: invitation.tournament_id,
+        })
+        .await
+        .map_err(|_| "Unable to check league members".to_string())?;
+
+        if is_user_in_league {
+            return Err("User is already in this league".to_string());
+        }
+
+        let pending_invitation =
+            LeagueRepository::fetch_pending_invitation_id(invitation.tournament_id, invitee_id)
+                .await
+                .map_err(|_| "Unable to check existing invitations".to_string())?;
+
+        if pending_invitation.is_some() {
+            return Err("User already has a pending invitation to this league".to_string());
+        }
+
+        LeagueRepository::insert_invitation(
+            invitation.tournament_id,
+            invitation.user_id,
+            invitee_id,
+        )
+        .await
+        .map_err(|_| "Unable to send invitation".to_string())?;
+
+        Ok(())
+    }
+
+    async fn fetch_pending_invitation(
+        invitation_id: i64,
+        user_id: i64,
+    ) -> Result<TournamentInvitation, String> {
+        let invitation = LeagueRepository::fetch_invitation(invitation_id)
+            .await
+            .map_err(|_| "Unable to find invitation".to_string())?;
+
+        if invitation.invitee_id != user_id {
+            return Err("Invitation belongs to another user".to_string());
+        }
+
+        if invitation.status != invitation_status::PENDING {
+            return Err("Invitation is no longer pending".to_string());
+        }
+
+        Ok(invitation)
+    }
+
+    pub async fn accept_invitation(
+        invitation_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<UserLeaguesResponse>, String> {
+        let invitation = Self::fetch_pending_invitation(invitation_id, user_id).await?;
+
+        let join_league = JoinLeague {
+            user_id,
+            tournament_id: invitation.tournament_id,
+        };
+
+        let is_user_in_league = LeagueRepository::fetch_is_user_in_league(&join_league)
+            .await
+            .map_err(|_| "Unable to check league members".to_string())?;
+
+        let is_joined = is_user_in_league
+            || Self::add_tournament_user(&join_league)
+                .await
+                .map_err(|_| "Unable to join league".to_string())?;
+
+        // A full league waitlists the user instead, the invitation stays pending and is
+        // accepted once they're promoted off the waitlist.
+        if is_joined {
+            LeagueRepository::update_invitation_status(invitation_id, invitation_status::ACCEPTED)
+                .await
+                .map_err(|_| "Unable to accept invitation".to_string())?;
+        }
+
+        Self::get_user_leagues(&UserLeaguesRequest { user_id })
+            .await
+            .map_err(|_| "Unable to get user leagues".to_string())
+    }
+
+    pub async fn decline_invitation(
+        invitation_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<UserLeaguesResponse>, String> {
+        let invitation = Self::fetch_pending_invitation(invitation_id, user_id).await?;
+
+        LeagueRepository::update_invitation_status(invitation_id, invitation_status::DECLINED)
+            .await
+            .map_err(|_| "Unable to decline invitation".to_string())?;
+
+        let waitlist_id = LeagueRepository::fetch_waitlist_id(&JoinLeague {
+            user_id,
+            tournament_id: invitation.tournament_id,
+        })
+        .await
+        .map_err(|_| "Unable to check waitlist".to_string())?;
+
+        if let Some(waitlist_id) = waitlist_id {
+            LeagueRepository::delete_waitlist_entry(waitlist_id)
+                .await
+                .map_err(|_| "Unable to leave waitlist".to_string())?;
+        }
+
+        Self::get_user_leagues(&UserLeaguesRequest { user_id })
+            .await
+            .map_err(|_| "Unable to get user leagues".to_string())
     }
 
     pub async fn swap_pick(pick_request: &SwapPickRequest) -> Result<(), String> {
@@ -72,10 +258,18 @@ impl LeagueService {
             return Err("Can't swap picks for an active competition".to_string());
         }
 
+        PickValidationService::validate_swap(pick_request)
+            .await
+            .map_err(|e| e.to_string())?;
+
         let previous_competitor =
             LeagueRepository::fetch_pick_competitor(pick_request.previous_pick_id)
                 .await
                 .map_err(|e| "Unable to get previous pick".to_string())?;
+        let (previous_slot, _) =
+            PickEventRepository::fetch_pick_slot(pick_request.previous_pick_id)
+                .await
+                .map_err(|_| "Unable to get previous pick".to_string())?;
 
         if pick_request.next_pick.next_pick_id.is_none() {
             LeagueRepository::delete_user_league_pick(pick_request.previous_pick_id)
@@ -87,21 +281,45 @@ impl LeagueService {
                 previous_competitor.competitor_id,
                 pick_request.next_pick.rank.unwrap(),
                 pick_request.next_pick.tournament_position_id.unwrap(),
+                previous_competitor.confidence,
+                previous_competitor.is_captain,
             )
             .await
             .map_err(|e| "Unable to get previous pick".to_string())?;
 
-            return if err.is_success() {
-                Ok(())
-            } else {
-                Err("Unable to insert top user league pick".to_string())
-            };
+            if !err.is_success() {
+                return Err("Unable to insert top user league pick".to_string());
+            }
+
+            Self::record_swap(
+                &previous_slot,
+                Some(previous_competitor.competitor_id),
+                None,
+            )
+            .await?;
+            Self::record_swap(
+                &PickSlotData {
+                    tournament_user_id: pick_request.tournament_user_id,
+                    tournament_position_id: pick_request.next_pick.tournament_position_id.unwrap(),
+                    workout_id: None,
+                    rank: pick_request.next_pick.rank,
+                },
+                None,
+                Some(previous_competitor.competitor_id),
+            )
+            .await?;
+
+            return Ok(());
         }
 
         let next_competitor =
             LeagueRepository::fetch_pick_competitor(pick_request.next_pick.next_pick_id.unwrap())
                 .await
                 .map_err(|e| "Unable to update pick".to_string())?;
+        let (next_slot, _) =
+            PickEventRepository::fetch_pick_slot(pick_request.next_pick.next_pick_id.unwrap())
+                .await
+                .map_err(|_| "Unable to update pick".to_string())?;
 
         LeagueRepository::update_pick_competitor(
             pick_request.previous_pick_id,
@@ -117,11 +335,149 @@ impl LeagueService {
         .await
         .map_err(|e| "Unable to update pick".to_string())?;
 
+        LeagueRepository::swap_pick_attributes(
+            pick_request.previous_pick_id,
+            pick_request.next_pick.next_pick_id.unwrap(),
+        )
+        .await
+        .map_err(|_| "Unable to update pick".to_string())?;
+
+        Self::record_swap(
+            &previous_slot,
+            Some(previous_competitor.competitor_id),
+            Some(next_competitor.competitor_id),
+        )
+        .await?;
+        Self::record_swap(
+            &next_slot,
+            Some(next_competitor.competitor_id),
+            Some(previous_competitor.competitor_id),
+        )
+        .await?;
+
         return Ok(());
     }
 
+    async fn record_swap(
+        slot: &PickSlotData,
+        previous_competitor_id: Option<i64>,
+        competitor_id: Option<i64>,
+    ) -> Result<(), String> {
+        PickEventService::record(
+            pick_action::SWAP,
+            pick_source::SWAP,
+            slot,
+            previous_competitor_id,
+            competitor_id,
+        )
+        .await
+        .map_err(|_| "Unable to record pick change".to_string())
+    }
+
+    // Top-N leagues have one captain per lineup, ShotCaller leagues one per workout.
+    pub async fn set_captain(captain_request: &SetCaptainRequest) -> Result<(), Error> {
+        let event_status = LeagueRepository::fetch_competition_tournament_status(
+            captain_request.tournament_user_id,
+        )
+        .await?;
+
+        if event_status.tournament_type_id != tournament_type::TOP_N
+            && event_status.tournament_type_id != tournament_type::SHOTCALLER
+        {
+            return Err(Error::Protocol(
+                "Captains are only used in Top-N and ShotCaller leagues".to_string(),
+            ));
+        }
+
+        if event_status.is_complete {
+            return Err(Error::Protocol(
+                "Can't update picks for a complete competition".to_string(),
+            ));
+        }
+
+        let (tournament_user_id, workout_id) =
+            LeagueRepository::fetch_pick_scope(captain_request.tournament_user_pick_id).await?;
+
+        if tournament_user_id != captain_request.tournament_user_id {
+            return Err(Error::Protocol(
+                "Pick doesn't belong to this member".to_string(),
+            ));
+        }
+
+        match workout_id {
+            Some(workout_id) => {
+                let workout = LeagueRepository::fetch_workout(workout_id).await?;
+
+                if workout.is_active || workout.is_complete {
+                    return Err(Error::Protocol(
+                        "Can't update picks for an active event".to_string(),
+                    ));
+                }
+            }
+            None if event_status.is_active => {
+                return Err(Error::Protocol(
+                    "Can't update picks for an active competition".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        LeagueRepository::update_pick_captain(
+            captain_request.tournament_user_id,
+            captain_request.tournament_user_pick_id,
+        )
+        .await
+    }
+
     pub async fn save_top_user_league_pick(
         pick_request: &CreateTopPickRequest,
+    ) -> Result<(), Error> {
+        Self::write_top_pick(pick_request, None).await
+    }
+
+    pub async fn save_confidence_pick(
+        pick_request: &CreateConfidencePickRequest,
+    ) -> Result<(), Error> {
+        Self::write_top_pick(&pick_request.pick, Some(pick_request.confidence)).await
+    }
+
+    // Weights run from 1 to the league's pick count and can only be used once per gender.
+    async fn validate_confidence(
+        pick_request: &CreateTopPickRequest,
+        gender_id: i64,
+        confidence: i64,
+    ) -> Result<(), Error> {
+        let pick_count = ConfidenceRepository::fetch_pick_count(pick_request.tournament_user_id)
+            .await?
+            .unwrap_or(0);
+
+        if confidence < 1 || confidence > pick_count {
+            return Err(Error::Protocol(format!(
+                "Confidence must be between 1 and {}",
+                pick_count
+            )));
+        }
+
+        let weights = ConfidenceRepository::fetch_confidence_weights(
+            pick_request.tournament_user_id,
+            gender_id,
+        )
+        .await?;
+
+        if weights.iter().any(|(tournament_position_id, weight)| {
+            *tournament_position_id != pick_request.tournament_position_id && *weight == confidence
+        }) {
+            return Err(Error::Protocol(
+                "Confidence weight is already used".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn write_top_pick(
+        pick_request: &CreateTopPickRequest,
+        confidence: Option<i64>,
     ) -> Result<(), Error> {
         let event_status =
             LeagueRepository::fetch_competition_tournament_status(pick_request.tournament_user_id)
@@ -133,12 +489,6 @@ impl LeagueService {
             ));
         }
 
-        if event_status.is_active {
-            return Err(Error::Protocol(
-                "Can't update picks for an active competition".to_string(),
-            ));
-        }
-
         if pick_request.competitor_id == 0
             || pick_request.rank == 0
             || pick_request.tournament_position_id == 0
@@ -146,9 +496,34 @@ impl LeagueService {
             return Err(Error::Protocol("Invalid Top Pick Request".to_string()));
         }
 
+        PickValidationService::validate_top_pick(
+            pick_request.tournament_user_id,
+            pick_request.competitor_id,
+            pick_request.tournament_position_id,
+            None,
+        )
+        .await?;
+
         let gender_id =
             LeagueRepository::fetch_competitor_gender_id(pick_request.competitor_id).await?;
 
+        let is_confidence = event_status.tournament_type_id == tournament_type::CONFIDENCE;
+
+        match confidence {
+            Some(_) if !is_confidence => {
+                return Err(Error::Protocol(
+                    "Confidence weights are only used in confidence leagues".to_string(),
+                ));
+            }
+            Some(weight) => Self::validate_confidence(pick_request, gender_id, weight).await?,
+            None if is_confidence => {
+                return Err(Error::Protocol(
+                    "Confidence leagues require a confidence weight".to_string(),
+                ));
+            }
+            None => {}
+        }
+
         let previous_pick = LeagueRepository::fetch_top_pick_id(
             pick_request.tournament_user_id,
             gender_id,
@@ -156,15 +531,50 @@ impl LeagueService {
         )
         .await?;
 
+        // Picks lock with the competition unless the athlete being replaced was pulled with a
+        // free swap.
+        if event_status.is_active && !CompetitorStatusService::is_free_swap(previous_pick).await? {
+            return Err(Error::Protocol(
+                "Can't update picks for an active competition".to_string(),
+            ));
+        }
+
+        let previous = match previous_pick {
+            Some(pick_id) => Some(LeagueRepository::fetch_pick_competitor(pick_id).await?),
+            None => None,
+        };
+        let previous_competitor_id = previous.as_ref().map(|p| p.competitor_id);
+
         if previous_pick.is_some() {
             LeagueRepository::delete_user_league_pick(previous_pick.unwrap()).await?;
         }
 
+        // The captain stays on the slot while the same athlete is still picked for it.
         LeagueRepository::insert_top_user_league_pick(
             pick_request.tournament_user_id,
             pick_request.competitor_id,
             pick_request.rank,
             pick_request.tournament_position_id,
+            confidence,
+            previous.is_some_and(|p| p.is_captain && p.competitor_id == pick_request.competitor_id),
+        )
+        .await?;
+
+        PickEventService::record(
+            pick_action::SAVE,
+            if confidence.is_some() {
+                pick_source::CONFIDENCE
+            } else {
+                pick_source::TOP
+            },
+            &PickSlotData {
+                tournament_user_id: pick_request.tournament_user_id,
+                tournament_position_id: pick_request.tournament_position_id,
+                workout_id: None,
+                rank: Some(pick_request.rank),
+            },
+            previous_competitor_id,
+            Some(pick_request.competitor_id),
         )
         .await?;
 
@@ -178,76 +588,435 @@ impl LeagueService {
             LeagueRepository::fetch_competition_tournament_status(pick_request.tournament_user_id)
                 .await?;
 
-        let workout = LeagueRepository::fetch_workout(pick_request.workout_id).await?;
+        let workout = LeagueRepository::fetch_workout(pick_request.workout_id).await?;
+
+        if event_status.is_complete {
+            return Err(Error::Protocol(
+                "Can't update picks for a complete competition".to_string(),
+            ));
+        }
+
+        let is_per_workout = Self::is_per_workout(event_status.tournament_type_id);
+
+        // An athlete pulled from the competition with a free swap can still be replaced
+        // once the workout is underway.
+        let is_free_swap = is_per_workout
+            && workout.is_active
+            && !workout.is_complete
+            && CompetitorStatusService::is_free_swap(
+                LeagueRepository::fetch_shot_caller_pick_id(
+                    pick_request.tournament_user_id,
+                    pick_request.workout_id,
+                    pick_request.tournament_position_id,
+                )
+                .await?,
+            )
+            .await?;
+
+        if is_per_workout && (workout.is_complete || (workout.is_active && !is_free_swap)) {
+            return Err(Error::Protocol(
+                "Can't update picks for an active event".to_string(),
+            ));
+        }
+
+        if pick_request.competitor_id == 0
+            || pick_request.workout_id == 0
+            || pick_request.tournament_position_id == 0
+        {
+            return Err(Error::Protocol(
+                "Invalid ShotCaller Pick Request".to_string(),
+            ));
+        }
+
+        PickValidationService::validate_shotcaller_pick(pick_request).await?;
+
+        if event_status.tournament_type_id == tournament_type::SURVIVOR {
+            SurvivorService::validate_pick(pick_request).await?;
+        }
+
+        let previous_pick = if event_status.tournament_type_id == tournament_type::PODIUM {
+            PodiumService::validate_pick(pick_request).await?;
+
+            PodiumRepository::fetch_podium_pick_id(
+                pick_request.tournament_user_id,
+                pick_request.workout_id,
+                pick_request.tournament_position_id,
+                pick_request.competitor_id,
+            )
+            .await?
+        } else {
+            LeagueRepository::fetch_shot_caller_pick_id(
+                pick_request.tournament_user_id,
+                pick_request.workout_id,
+                pick_request.tournament_position_id,
+            )
+            .await?
+        };
+
+        let previous = match previous_pick {
+            Some(pick_id) => Some(LeagueRepository::fetch_pick_competitor(pick_id).await?),
+            None => None,
+        };
+        let previous_competitor_id = previous.as_ref().map(|p| p.competitor_id);
+
+        if previous_pick.is_some() {
+            LeagueRepository::delete_user_league_pick(previous_pick.unwrap()).await?;
+        }
+
+        LeagueRepository::insert_user_league_pick(
+            pick_request.tournament_user_id,
+            pick_request.competitor_id,
+            pick_request.workout_id,
+            pick_request.tournament_position_id,
+            previous.is_some_and(|p| p.is_captain && p.competitor_id == pick_request.competitor_id),
+        )
+        .await?;
+
+        PickEventService::record(
+            pick_action::SAVE,
+            pick_source::SHOTCALLER,
+            &PickSlotData {
+                tournament_user_id: pick_request.tournament_user_id,
+                tournament_position_id: pick_request.tournament_position_id,
+                workout_id: Some(pick_request.workout_id),
+                rank: None,
+            },
+            previous_competitor_id,
+            Some(pick_request.competitor_id),
+        )
+        .await?;
+
+        return Ok(());
+    }
+
+    pub async fn delete_tournament(
+        tournament_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<UserLeaguesResponse>, DeleteTournamentError> {
+        let commissioner_id =
+            LeagueRepository::fetch_tournament_commissioner_id(tournament_id).await?;
+
+        if commissioner_id != user_id {
+            return Err(DeleteTournamentError::NotCommissioner);
+        }
+
+        LeagueRepository::delete_tournament(tournament_id).await?;
+
+        let user_leagues =
+            LeagueRepository::fetch_user_leagues(&UserLeaguesRequest { user_id }).await?;
+
+        return Ok(user_leagues);
+    }
+
+    async fn remove_tournament_user(
+        tournament_user_id: i64,
+        source: &str,
+        activity: Option<&LeagueActivityData>,
+    ) -> Result<(), Error> {
+        let member = LeagueRepository::fetch_tournament_member(tournament_user_id).await?;
+
+        LeagueRepository::delete_tournament_user(tournament_user_id, source, activity).await?;
+
+        Self::promote_waitlist(member.tournament_id).await
+    }
+
+    pub async fn delete_tournament_user(
+        tournament_user_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<UserLeaguesResponse>, Error> {
+        Self::remove_tournament_user(tournament_user_id, pick_source::LEAVE_LEAGUE, None).await?;
+
+        let user_leagues =
+            LeagueRepository::fetch_user_leagues(&UserLeaguesRequest { user_id }).await?;
+
+        return Ok(user_leagues);
+    }
+
+    async fn fetch_commissioner_tournament(
+        tournament_id: i64,
+        user_id: i64,
+    ) -> Result<Tournament, String> {
+        let tournament = LeagueRepository::fetch_tournament(tournament_id)
+            .await
+            .map_err(|_| "Unable to find league".to_string())?;
+
+        if tournament.commissioner_id as i64 != user_id {
+            return Err("Only the commissioner can manage this league".to_string());
+        }
+
+        Ok(tournament)
+    }
+
+    async fn fetch_league_member(
+        tournament_id: i64,
+        tournament_user_id: i64,
+    ) -> Result<TournamentMemberData, String> {
+        let member = LeagueRepository::fetch_tournament_member(tournament_user_id)
+            .await
+            .map_err(|_| "Unable to find league member".to_string())?;
+
+        if member.tournament_id != tournament_id {
+            return Err("User is not a member of this league".to_string());
+        }
+
+        Ok(member)
+    }
+
+    fn get_league_activity_data(
+        tournament_id: i64,
+        user_id: i64,
+        action: &str,
+        detail: Option<String>,
+    ) -> LeagueActivityData {
+        LeagueActivityData {
+            tournament_id,
+            user_id,
+            action: action.to_string(),
+            detail,
+        }
+    }
+
+    async fn get_user_league(
+        user_id: i64,
+        tournament_id: i64,
+    ) -> Result<UserLeaguesResponse, String> {
+        let leagues = LeagueRepository::fetch_user_leagues(&UserLeaguesRequest { user_id })
+            .await
+            .map_err(|_| "Unable to get user leagues".to_string())?;
+
+        leagues
+            .into_iter()
+            .find(|l| l.tournament_id as i64 == tournament_id)
+            .ok_or("Unable to find league".to_string())
+    }
+
+    // These leagues pick per workout, so each pick locks when its workout starts.
+    fn is_per_workout(tournament_type_id: i64) -> bool {
+        tournament_type_id == tournament_type::SHOTCALLER
+            || tournament_type_id == tournament_type::SURVIVOR
+            || tournament_type_id == tournament_type::PODIUM
+    }
+
+    fn generate_passcode() -> String {
+        uuid::Uuid::new_v4().simple().to_string()[..6].to_uppercase()
+    }
+
+    pub async fn get_league_activity(
+        tournament_id: i64,
+    ) -> Result<Vec<LeagueActivityResponse>, Error> {
+        LeagueRepository::fetch_league_activity(tournament_id).await
+    }
+
+    pub async fn rename_league(
+        commissioner: &CommissionerRequest,
+        name: &str,
+    ) -> Result<UserLeaguesResponse, String> {
+        let tournament =
+            Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+                .await?;
+
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::RENAME,
+            Some(format!("{} -> {}", tournament.name, name.trim())),
+        );
+
+        LeagueRepository::update_tournament_name(commissioner.tournament_id, name, &activity)
+            .await
+            .map_err(|_| "Unable to rename league".to_string())?;
+
+        Self::get_user_league(commissioner.user_id, commissioner.tournament_id).await
+    }
+
+    pub async fn update_league_logo(
+        commissioner: &CommissionerRequest,
+        logo: &str,
+    ) -> Result<UserLeaguesResponse, String> {
+        Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+            .await?;
+
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::LOGO,
+            Some(logo.trim().to_string()),
+        );
+
+        LeagueRepository::update_tournament_logo(commissioner.tournament_id, logo, &activity)
+            .await
+            .map_err(|_| "Unable to update league logo".to_string())?;
+
+        Self::get_user_league(commissioner.user_id, commissioner.tournament_id).await
+    }
+
+    pub async fn update_league_privacy(
+        commissioner: &CommissionerRequest,
+        is_private: bool,
+    ) -> Result<UserLeaguesResponse, String> {
+        let tournament =
+            Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+                .await?;
+
+        // A private league can only be joined with a passcode, so make sure one exists.
+        let passcode = if is_private {
+            tournament.passcode.or(Some(Self::generate_passcode()))
+        } else {
+            tournament.passcode
+        };
+
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::PRIVACY,
+            Some(if is_private { "private" } else { "public" }.to_string()),
+        );
+
+        LeagueRepository::update_tournament_privacy(
+            commissioner.tournament_id,
+            is_private,
+            passcode,
+            &activity,
+        )
+        .await
+        .map_err(|_| "Unable to update league privacy".to_string())?;
+
+        Self::get_user_league(commissioner.user_id, commissioner.tournament_id).await
+    }
+
+    pub async fn rotate_league_passcode(
+        commissioner: &CommissionerRequest,
+    ) -> Result<String, String> {
+        let tournament =
+            Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+                .await?;
+
+        let passcode = Self::generate_passcode();
+
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::PASSCODE,
+            None,
+        );
+
+        LeagueRepository::update_tournament_privacy(
+            commissioner.tournament_id,
+            tournament.is_private,
+            Some(passcode.clone()),
+            &activity,
+        )
+        .await
+        .map_err(|_| "Unable to rotate league passcode".to_string())?;
+
+        Ok(passcode)
+    }
+
+    pub async fn remove_league_member(
+        commissioner: &CommissionerMemberRequest,
+    ) -> Result<(), String> {
+        Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+            .await?;
 
-        if event_status.is_complete {
-            return Err(Error::Protocol(
-                "Can't update picks for a complete competition".to_string(),
-            ));
-        }
+        let member =
+            Self::fetch_league_member(commissioner.tournament_id, commissioner.tournament_user_id)
+                .await?;
 
-        if event_status.tournament_type_id == 2 && (workout.is_active || workout.is_complete) {
-            return Err(Error::Protocol(
-                "Can't update picks for an active event".to_string(),
-            ));
+        if member.user_id == commissioner.user_id {
+            return Err("Commissioner can't be removed from the league".to_string());
         }
 
-        if pick_request.competitor_id == 0
-            || pick_request.workout_id == 0
-            || pick_request.tournament_position_id == 0
-        {
-            return Err(Error::Protocol(
-                "Invalid ShotCaller Pick Request".to_string(),
-            ));
-        }
+        let member_user = AppUserRepository::fetch_user_by_user_id(member.user_id as u64)
+            .await
+            .map_err(|_| "Unable to find league member".to_string())?;
 
-        let previous_pick = LeagueRepository::fetch_shot_caller_pick_id(
-            pick_request.tournament_user_id,
-            pick_request.workout_id,
-            pick_request.tournament_position_id,
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::REMOVE_MEMBER,
+            Some(member_user.username),
+        );
+
+        Self::remove_tournament_user(
+            member.tournament_user_id,
+            pick_source::REMOVE_MEMBER,
+            Some(&activity),
         )
-        .await?;
+        .await
+        .map_err(|_| "Unable to remove league member".to_string())
+    }
 
-        if previous_pick.is_some() {
-            LeagueRepository::delete_user_league_pick(previous_pick.unwrap()).await?;
+    pub async fn update_league_capacity(
+        commissioner: &CommissionerRequest,
+        max_entries: Option<i64>,
+    ) -> Result<UserLeaguesResponse, String> {
+        Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+            .await?;
+
+        let entries = LeagueRepository::fetch_tournament_user_count(commissioner.tournament_id)
+            .await
+            .map_err(|_| "Unable to count league entries".to_string())?;
+
+        if max_entries.is_some_and(|m| m < entries) {
+            return Err("League capacity can't be lower than its current entries".to_string());
         }
 
-        LeagueRepository::insert_user_league_pick(
-            pick_request.tournament_user_id,
-            pick_request.competitor_id,
-            pick_request.workout_id,
-            pick_request.tournament_position_id,
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::CAPACITY,
+            Some(max_entries.map_or("unlimited".to_string(), |m| m.to_string())),
+        );
+
+        LeagueRepository::update_tournament_max_entries(
+            commissioner.tournament_id,
+            max_entries,
+            &activity,
         )
-        .await?;
+        .await
+        .map_err(|_| "Unable to update league capacity".to_string())?;
 
-        return Ok(());
+        Self::promote_waitlist(commissioner.tournament_id)
+            .await
+            .map_err(|_| "Unable to promote waitlisted users".to_string())?;
+
+        Self::get_user_league(commissioner.user_id, commissioner.tournament_id).await
     }
 
-    pub async fn delete_tournament(
-        tournament_id: i64,
-        user_id: i64,
-    ) -> Result<Vec<UserLeaguesResponse>, Error> {
-        LeagueRepository::delete_tournament_picks(tournament_id).await?;
-        LeagueRepository::delete_tournament_users(tournament_id).await?;
-        LeagueRepository::delete_tournament_positions(tournament_id).await?;
-        LeagueRepository::delete_tournament(tournament_id).await?;
+    pub async fn transfer_commissioner(
+        commissioner: &CommissionerMemberRequest,
+    ) -> Result<UserLeaguesResponse, String> {
+        Self::fetch_commissioner_tournament(commissioner.tournament_id, commissioner.user_id)
+            .await?;
 
-        let user_leagues =
-            LeagueRepository::fetch_user_leagues(&UserLeaguesRequest { user_id }).await?;
+        let member =
+            Self::fetch_league_member(commissioner.tournament_id, commissioner.tournament_user_id)
+                .await?;
 
-        return Ok(user_leagues);
-    }
-    pub async fn delete_tournament_user(
-        tournament_user_id: i64,
-        user_id: i64,
-    ) -> Result<Vec<UserLeaguesResponse>, Error> {
-        LeagueRepository::delete_tournament_user_picks(tournament_user_id).await?;
-        LeagueRepository::delete_tournament_user(tournament_user_id).await?;
+        if member.user_id == commissioner.user_id {
+            return Err("User is already the commissioner".to_string());
+        }
 
-        let user_leagues =
-            LeagueRepository::fetch_user_leagues(&UserLeaguesRequest { user_id }).await?;
+        let member_user = AppUserRepository::fetch_user_by_user_id(member.user_id as u64)
+            .await
+            .map_err(|_| "Unable to find league member".to_string())?;
 
-        return Ok(user_leagues);
+        let activity = Self::get_league_activity_data(
+            commissioner.tournament_id,
+            commissioner.user_id,
+            league_activity::TRANSFER_COMMISSIONER,
+            Some(member_user.username),
+        );
+
+        LeagueRepository::update_tournament_commissioner(
+            commissioner.tournament_id,
+            member.user_id,
+            &activity,
+        )
+        .await
+        .map_err(|_| "Unable to transfer commissioner".to_string())?;
+
+        Self::get_user_league(commissioner.user_id, commissioner.tournament_id).await
     }
 
     pub async fn delete_user_league_top_pick(tournament_user_pick_id: i64) -> Result<(), Error> {
@@ -267,8 +1036,20 @@ impl LeagueService {
             ));
         }
 
+        let (slot, competitor_id) =
+            PickEventRepository::fetch_pick_slot(tournament_user_pick_id).await?;
+
         LeagueRepository::delete_user_league_pick(tournament_user_pick_id).await?;
 
+        PickEventService::record(
+            pick_action::DELETE,
+            pick_source::DELETE_TOP,
+            &slot,
+            Some(competitor_id),
+            None,
+        )
+        .await?;
+
         return Ok(());
     }
 
@@ -284,16 +1065,31 @@ impl LeagueService {
             ));
         }
 
-        if event_status.tournament_type_id == 1
-            || (event_status.tournament_type_id == 2 && (workout.is_active || workout.is_complete))
+        // Per-workout picks lock with their workout, which also keeps a used Survivor competitor
+        // from being freed up again.
+        if event_status.tournament_type_id == tournament_type::TOP_N
+            || (Self::is_per_workout(event_status.tournament_type_id)
+                && (workout.is_active || workout.is_complete))
         {
             return Err(Error::Protocol(
                 "Can't delete picks for an active event".to_string(),
             ));
         }
 
+        let (slot, competitor_id) =
+            PickEventRepository::fetch_pick_slot(tournament_user_pick_id).await?;
+
         LeagueRepository::delete_user_league_pick(tournament_user_pick_id).await?;
 
+        PickEventService::record(
+            pick_action::DELETE,
+            pick_source::DELETE_SHOTCALLER,
+            &slot,
+            Some(competitor_id),
+            None,
+        )
+        .await?;
+
         return Ok(());
     }
 
@@ -345,17 +1141,45 @@ impl LeagueService {
         //     LeagueRepository::fetch_competition_leaderboard(metadata.competition_id as i64, 2)
         //         .await?;
 
-        let leaderboard = LeaderboardResponse {
+        let mut leaderboard = LeaderboardResponse {
             tournament: metadata.tournament_name,
             competition: metadata.competition_name,
             logo: metadata.competition_logo,
             locked_events: metadata.locked_events,
-            leaderboard: if metadata.tournament_type_id == 1 {
+            leaderboard: if metadata.tournament_type_id as i64 == tournament_type::TOP_N {
                 LeagueRepository::fetch_top_10_leaderboard(
                     *tournament_id,
                     metadata.competition_id as i64,
                 )
                 .await?
+            } else if metadata.tournament_type_id as i64 == tournament_type::SNAKE_DRAFT
+                || metadata.tournament_type_id as i64 == tournament_type::AUCTION_DRAFT
+            {
+                RosterRepository::fetch_roster_leaderboard(
+                    *tournament_id,
+                    metadata.competition_id as i64,
+                )
+                .await?
+            } else if metadata.tournament_type_id as i64 == tournament_type::SURVIVOR {
+                SurvivorService::get_survivor_leaderboard(*tournament_id).await?
+            } else if metadata.tournament_type_id as i64 == tournament_type::CONFIDENCE {
+                ConfidenceRepository::fetch_confidence_leaderboard(
+                    *tournament_id,
+                    metadata.competition_id as i64,
+                )
+                .await?
+            } else if metadata.tournament_type_id as i64 == tournament_type::SALARY_CAP {
+                SalaryRepository::fetch_salary_leaderboard(
+                    *tournament_id,
+                    metadata.competition_id as i64,
+                )
+                .await?
+            } else if metadata.tournament_type_id as i64 == tournament_type::PODIUM {
+                PodiumRepository::fetch_podium_leaderboard(
+                    *tournament_id,
+                    metadata.competition_id as i64,
+                )
+                .await?
             } else {
                 LeagueRepository::fetch_shotcaller_leaderboard(
                     *tournament_id,
@@ -365,9 +1189,71 @@ impl LeagueService {
             },
         };
 
+        if metadata.tournament_type_id as i64 == tournament_type::TOP_N {
+            let event_points = LeagueRepository::fetch_top_10_event_points(
+                *tournament_id,
+                metadata.competition_id as i64,
+            )
+            .await?;
+
+            Self::apply_leaderboard_events(&mut leaderboard.leaderboard, event_points, true);
+        } else if metadata.tournament_type_id as i64 == tournament_type::SHOTCALLER {
+            let event_points = LeagueRepository::fetch_shotcaller_event_points(
+                *tournament_id,
+                metadata.competition_id as i64,
+            )
+            .await?;
+
+            Self::apply_leaderboard_events(&mut leaderboard.leaderboard, event_points, false);
+        }
+
         Ok(leaderboard)
     }
 
+    // Builds each member's standings after every scored workout. Top-N points are running
+    // totals, so the event points are the change since the previous workout.
+    fn apply_leaderboard_events(
+        leaderboard: &mut [LeaderboardEntry],
+        event_points: Vec<(i64, i64, f64)>,
+        is_cumulative: bool,
+    ) {
+        let ordinals: BTreeSet<i64> = event_points.iter().map(|e| e.1).collect();
+        let points: HashMap<(i64, i64), f64> = event_points
+            .into_iter()
+            .map(|(tournament_user_id, ordinal, points)| ((tournament_user_id, ordinal), points))
+            .collect();
+
+        for ordinal in ordinals {
+            let totals: Vec<(f64, f64)> = leaderboard
+                .iter()
+                .map(|entry| {
+                    let previous_total = entry.events.last().map_or(0.0, |e| e.total_points);
+                    let event_points = points.get(&(entry.tournament_user_id as i64, ordinal));
+
+                    if is_cumulative {
+                        let total = event_points.copied().unwrap_or(previous_total);
+                        (total - previous_total, total)
+                    } else {
+                        let event_points = event_points.copied().unwrap_or(0.0);
+                        (event_points, previous_total + event_points)
+                    }
+                })
+                .collect();
+
+            for (i, entry) in leaderboard.iter_mut().enumerate() {
+                let rank = totals.iter().filter(|t| t.1 > totals[i].1).count() as i64 + 1;
+
+                entry.events.push(LeaderboardEvent {
+                    ordinal,
+                    points: totals[i].0,
+                    total_points: totals[i].1,
+                    rank,
+                    movement: entry.events.last().map_or(0, |e| e.rank - rank),
+                });
+            }
+        }
+    }
+
     fn get_top_10_picks(
         tournament_type_id: i64,
         leaderboard: &HashMap<i64, CompetitionLeaderboardResponse>,
@@ -393,15 +1279,16 @@ impl LeagueService {
                 competitor_id: p.competitor_id.clone() as u64,
                 points: if tournament_type_id == 1 { 10.0 } else { 100.0 },
                 event_points: p.points,
-                is_withdrawn: false,
-                is_cut: false,
-                is_suspended: false,
+                is_withdrawn: p.is_withdrawn,
+                is_cut: p.is_cut,
+                is_suspended: p.is_suspended,
                 is_final: false,
+                is_captain: false,
             })
             .collect()
     }
 
-    fn get_matchup_picks(
+    pub(crate) fn get_matchup_picks(
         picks: Vec<LeaderboardPicks>,
         leaderboard: &HashMap<i64, CompetitionLeaderboardResponse>,
     ) -> Vec<MatchupPick> {
@@ -427,6 +1314,10 @@ impl LeagueService {
                     if points < 0.0 {
                         points = 0.0;
                     }
+
+                    if p.is_captain {
+                        points *= captain::MULTIPLIER;
+                    }
                 }
                 // } else {
                 //     points = competitor_leaderboard
@@ -436,19 +1327,6 @@ impl LeagueService {
                 //         .clone();
                 // };
 
-                // let withdrawn_ids = vec![&346866, &73659i64, &298928i64, &360720i64];
-                // let is_withdrawn = withdrawn_ids
-                //     .iter()
-                //     .any(|w| w == *competitor_leaderboard.competition_id);
-                // if competitor_leaderboard.competitor_id == 346866
-                // // || competitor_leaderboard.competitor_id == 73659
-                // // || competitor_leaderboard.competitor_id == 298928
-                // // || competitor_leaderboard.competitor_id == 360720
-                // {
-                //     true
-                // } else {
-                //     false
-                // };
                 MatchupPick {
                     predicted_rank: p.rank as u64,
                     rank: competitor_leaderboard.placement as u64,
@@ -458,9 +1336,12 @@ impl LeagueService {
                     points,
                     event_points: competitor_leaderboard.points,
                     is_withdrawn: competitor_leaderboard.is_withdrawn,
-                    is_cut: false,
-                    is_suspended: false,
-                    is_final: competitor_leaderboard.is_withdrawn,
+                    is_cut: competitor_leaderboard.is_cut,
+                    is_suspended: competitor_leaderboard.is_suspended,
+                    is_final: competitor_leaderboard.is_withdrawn
+                        || competitor_leaderboard.is_cut
+                        || competitor_leaderboard.is_suspended,
+                    is_captain: p.is_captain,
                 }
             })
             .collect()
@@ -480,6 +1361,29 @@ impl LeagueService {
             .await
     }
 
+    // Loads the tournament's metadata with the men's and women's competition leaderboards.
+    pub(crate) async fn fetch_matchup_leaderboards(
+        tournament_id: i64,
+    ) -> Result<
+        (
+            LeaderboardMetadataData,
+            HashMap<i64, CompetitionLeaderboardResponse>,
+            HashMap<i64, CompetitionLeaderboardResponse>,
+        ),
+        Error,
+    > {
+        let metadata = LeagueRepository::fetch_competition(tournament_id).await?;
+
+        let men_leaderboard =
+            LeagueRepository::fetch_competition_leaderboard(metadata.competition_id as i64, 1)
+                .await?;
+        let women_leaderboard =
+            LeagueRepository::fetch_competition_leaderboard(metadata.competition_id as i64, 2)
+                .await?;
+
+        Ok((metadata, men_leaderboard, women_leaderboard))
+    }
+
     pub async fn get_leaderboard_matchup(
         tournament_id: &i64,
         user_id: &i64,
@@ -490,19 +1394,13 @@ impl LeagueService {
             tournament_id, user_id, competitor_id
         );
 
-        let metadata = LeagueRepository::fetch_competition(*tournament_id).await?;
+        let (metadata, men_leaderboard, women_leaderboard) =
+            Self::fetch_matchup_leaderboards(*tournament_id).await?;
 
         let tournament_users =
             LeagueRepository::fetch_matchup_users(*tournament_id, *user_id, *competitor_id).await?;
 
-        let men_leaderboard =
-            LeagueRepository::fetch_competition_leaderboard(metadata.competition_id as i64, 1)
-                .await?;
-        let women_leaderboard =
-            LeagueRepository::fetch_competition_leaderboard(metadata.competition_id as i64, 2)
-                .await?;
-
-        let user_picks = tournament_users
+        let mut user_picks = tournament_users
             .iter()
             .find(|tu| tu.tournament_user_id as i64 == *user_id)
             .unwrap_or(&LeaderboardTournamentUserData {
@@ -514,7 +1412,7 @@ impl LeagueService {
             })
             .clone();
 
-        let competitor_picks = tournament_users
+        let mut competitor_picks = tournament_users
             .iter()
             .find(|tu| tu.tournament_user_id as i64 == *competitor_id)
             .unwrap_or(&LeaderboardTournamentUserData {
@@ -526,6 +1424,11 @@ impl LeagueService {
             })
             .clone();
 
+        if !Self::is_competition_locked(*tournament_id).await? {
+            Self::hide_matchup_picks(&mut user_picks);
+            Self::hide_matchup_picks(&mut competitor_picks);
+        }
+
         let user_men_players =
             Self::get_matchup_picks(user_picks.men_competitor_ids.clone(), &men_leaderboard);
 
@@ -568,24 +1471,227 @@ impl LeagueService {
         Ok(leaderboard)
     }
 
+    fn parse_matchup_ids(tournament_user_ids: &str) -> Result<Vec<i64>, Error> {
+        let mut ids: Vec<i64> = vec![];
+
+        for id in tournament_user_ids.split(',') {
+            let id: i64 = id
+                .trim()
+                .parse()
+                .map_err(|_| Error::Protocol("Invalid tournament user id".to_string()))?;
+
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+
+        if ids.len() < 2 || ids.len() > 5 {
+            return Err(Error::Protocol(
+                "A matchup compares between 2 and 5 members".to_string(),
+            ));
+        }
+
+        Ok(ids)
+    }
+
+    // Top-N lineups are hidden in matchups until the competition starts. Members see their
+    // own picks before then through the picks endpoint.
+    pub(crate) async fn is_competition_locked(tournament_id: i64) -> Result<bool, Error> {
+        let status =
+            LeagueRepository::fetch_competition_tournament_status_by_tournament(tournament_id)
+                .await?;
+
+        Ok(status.is_active || status.is_complete)
+    }
+
+    pub(crate) fn hide_matchup_picks(member: &mut LeaderboardTournamentUserData) {
+        member.men_competitor_ids = vec![];
+        member.women_competitor_ids = vec![];
+    }
+
+    // Picks are aligned into slots (gender and rank for Top-N, workout and position for
+    // ShotCaller) with one entry per member, in the order the members were requested. Every
+    // member's picks stay hidden until they lock.
+    pub async fn get_multi_matchup(
+        tournament_id: i64,
+        req: &MultiMatchupRequest,
+    ) -> Result<MultiMatchupResponse, Error> {
+        let ids = Self::parse_matchup_ids(&req.tournament_user_ids)?;
+        let metadata = LeagueRepository::fetch_competition(tournament_id).await?;
+        let tournament_type_id = metadata.tournament_type_id as i64;
+
+        let matchup_members = LeagueRepository::fetch_matchup_members(tournament_id, &ids).await?;
+        let mut members: Vec<LeaderboardTournamentUserData> = vec![];
+
+        for id in &ids {
+            let Some(member) = matchup_members
+                .iter()
+                .find(|m| m.tournament_user_id as i64 == *id)
+            else {
+                return Err(Error::Protocol("League member not found".to_string()));
+            };
+
+            members.push(member.clone());
+        }
+
+        let mut workouts: Vec<WorkoutResponse> = vec![];
+        let mut slots: Vec<MultiMatchupSlot> = vec![];
+
+        if tournament_type_id == tournament_type::TOP_N {
+            let (_, men_leaderboard, women_leaderboard) =
+                Self::fetch_matchup_leaderboards(tournament_id).await?;
+
+            if !Self::is_competition_locked(tournament_id).await? {
+                members.iter_mut().for_each(Self::hide_matchup_picks);
+            }
+
+            for (gender_id, leaderboard) in [(1, &men_leaderboard), (2, &women_leaderboard)] {
+                let players: Vec<Vec<MatchupPick>> = members
+                    .iter()
+                    .map(|m| {
+                        let picks = if gender_id == 1 {
+                            m.men_competitor_ids.clone()
+                        } else {
+                            m.women_competitor_ids.clone()
+                        };
+
+                        Self::get_matchup_picks(picks, leaderboard)
+                    })
+                    .collect();
+
+                let mut ranks: Vec<u64> = players
+                    .iter()
+                    .flat_map(|p| p.iter().map(|pick| pick.predicted_rank))
+                    .collect();
+                ranks.sort();
+                ranks.dedup();
+
+                slots.extend(ranks.into_iter().map(|rank| {
+                    MultiMatchupSlot {
+                        gender_id: Some(gender_id),
+                        rank: Some(rank as i64),
+                        workout_id: None,
+                        tournament_position_id: None,
+                        picks: players
+                            .iter()
+                            .map(|p| {
+                                p.iter()
+                                    .find(|pick| pick.predicted_rank == rank)
+                                    .map(|pick| MultiMatchupPick {
+                                        competitor_id: pick.competitor_id as i64,
+                                        first_name: pick.first_name.clone(),
+                                        last_name: pick.last_name.clone(),
+                                        points: pick.points,
+                                        is_captain: pick.is_captain,
+                                    })
+                            })
+                            .collect(),
+                    }
+                }));
+            }
+        } else if tournament_type_id == tournament_type::SHOTCALLER {
+            workouts = LeagueRepository::fetch_workouts_by_tournament(tournament_id).await?;
+            let mut picks =
+                LeagueRepository::fetch_shotcaller_picks(tournament_id, &ids, false).await?;
+            let players: Vec<Vec<MatchupShotcallerPick>> = ids
+                .iter()
+                .map(|id| picks.remove(id).unwrap_or_default())
+                .collect();
+
+            let workout_ordinal = |workout_id: i64| {
+                workouts
+                    .iter()
+                    .find(|w| w.id == workout_id)
+                    .map_or(0, |w| w.ordinal)
+            };
+
+            let mut keys: Vec<(i64, i64)> = players
+                .iter()
+                .flat_map(|p| {
+                    p.iter()
+                        .map(|pick| (pick.workout_id, pick.tournament_position_id))
+                })
+                .collect();
+            keys.sort_by_key(|(workout_id, position_id)| {
+                (workout_ordinal(*workout_id), *position_id)
+            });
+            keys.dedup();
+
+            slots.extend(keys.into_iter().map(|(workout_id, position_id)| {
+                MultiMatchupSlot {
+                    gender_id: None,
+                    rank: None,
+                    workout_id: Some(workout_id),
+                    tournament_position_id: Some(position_id),
+                    picks: players
+                        .iter()
+                        .map(|p| {
+                            p.iter()
+                                .find(|pick| {
+                                    pick.workout_id == workout_id
+                                        && pick.tournament_position_id == position_id
+                                })
+                                .map(|pick| MultiMatchupPick {
+                                    competitor_id: pick.competitor_id,
+                                    first_name: pick.first_name.clone(),
+                                    last_name: pick.last_name.clone(),
+                                    points: pick.points,
+                                    is_captain: pick.is_captain,
+                                })
+                        })
+                        .collect(),
+                }
+            }));
+        } else {
+            return Err(Error::Protocol(
+                "Matchups are only available for Top-N and ShotCaller leagues".to_string(),
+            ));
+        }
+
+        let members = members
+            .into_iter()
+            .enumerate()
+            .map(|(i, m)| MultiMatchupMember {
+                tournament_user_id: m.tournament_user_id as i64,
+                display_name: m.display_name,
+                avatar: m.avatar,
+                points: slots
+                    .iter()
+                    .filter_map(|s| s.picks[i].as_ref())
+                    .map(|p| p.points)
+                    .sum(),
+            })
+            .collect();
+
+        Ok(MultiMatchupResponse {
+            tournament_type_id,
+            locked_events: metadata.locked_events,
+            workouts,
+            members,
+            slots,
+        })
+    }
+
     pub async fn get_shotcaller_leaderboard_matchup(
         tournament_id: &i64,
         user_id: &i64,
         competitor_id: &i64,
     ) -> Result<LeaderboardMatchupShotcallerResponse, Error> {
+        let tournament_user_ids = [*user_id, *competitor_id];
+
         let res = join!(
             LeagueRepository::fetch_workouts_by_tournament(*tournament_id),
-            LeagueRepository::fetch_shotcaller_picks(*tournament_id, *user_id),
-            LeagueRepository::fetch_shotcaller_picks(*tournament_id, *competitor_id),
+            LeagueRepository::fetch_shotcaller_picks(*tournament_id, &tournament_user_ids, false),
             PropsRepository::fetch_prop_matchup(*user_id),
             PropsRepository::fetch_prop_matchup(*competitor_id),
         );
 
         let workouts = res.0.unwrap();
-        let user_picks = res.1.unwrap();
-        let competitor_picks = res.2.unwrap();
+        let mut picks = res.1.unwrap();
+        let user_picks = picks.remove(user_id).unwrap_or_default();
+        let competitor_picks = picks.remove(competitor_id).unwrap_or_default();
         let user_prop_picks = res
-            .3
+            .2
             .unwrap_or(PropUserMatchup {
                 display_name: "".to_string(),
                 avatar: "".to_string(),
@@ -596,7 +1702,7 @@ impl LeagueService {
             .picks;
 
         let competitor_prop_picks = res
-            .4
+            .3
             .unwrap_or(PropUserMatchup {
                 display_name: "".to_string(),
                 avatar: "".to_string(),
@@ -648,6 +1754,8 @@ impl LeagueService {
                 finishes: vec![],
                 placement: 0,
                 is_withdrawn: false,
+                is_cut: false,
+                is_suspended: false,
             })
             .clone()
     }
@@ -842,6 +1950,8 @@ impl LeagueService {
                 id: p.id,
                 competitor_id: p.competitor_id,
                 rank: p.rank,
+                confidence: p.confidence,
+                is_captain: p.is_captain,
             })
             .collect()
     }
@@ -978,6 +2088,7 @@ impl LeagueService {
                 competitor_id: p.competitor_id,
                 workout_id: p.workout_id.unwrap_or(0),
                 position_id: p.tournament_position_id,
+                is_captain: p.is_captain,
             })
             .collect();
 
@@ -1104,6 +2215,23 @@ impl LeagueService {
         LeagueRepository::fetch_league_athletes(*competition_id).await
     }
     pub async fn create_league(league: &CreateLeague) -> Result<UserLeaguesResponse, Error> {
+        let is_draft = league.tournament_type_id as i64 == tournament_type::SNAKE_DRAFT;
+
+        if is_draft && league.pick_count.is_none() {
+            return Err(Error::Protocol(
+                "Draft leagues require a pick count".to_string(),
+            ));
+        }
+
+        let is_ranked = league.tournament_type_id as i64 == tournament_type::TOP_N
+            || league.tournament_type_id as i64 == tournament_type::CONFIDENCE;
+
+        if is_ranked && league.pick_count.is_none() {
+            return Err(Error::Protocol(
+                "Ranked leagues require a pick count".to_string(),
+            ));
+        }
+
         let new_league = Tournament {
             id: 0,
             competition_id: league.competition_id,
@@ -1117,21 +2245,39 @@ impl LeagueService {
             competition: None,
             tournament_type: None,
             pick_count: league.pick_count,
+            max_entries: league.max_entries,
         };
 
         let league_id = LeagueRepository::insert_tournament(new_league).await?;
         LeagueRepository::insert_tournament_user(league_id as i64, league.user_id as i64).await?;
 
-        if league.tournament_type_id == 1 {
+        if is_draft {
+            DraftRepository::insert_draft(league_id as i64, DEFAULT_PICK_SECONDS).await?;
+        } else if is_ranked {
             for i in 1..=league.pick_count.unwrap() {
                 LeagueRepository::insert_tournament_position(league_id as i64, i + 5, i).await?;
             }
+        } else if league.tournament_type_id as i64 == tournament_type::SURVIVOR {
+            LeagueRepository::insert_tournament_position(league_id as i64, 5, 1).await?;
+        } else if league.tournament_type_id as i64 == tournament_type::PODIUM {
+            for i in 1..=3i64 {
+                LeagueRepository::insert_tournament_position(league_id as i64, i + 5, i).await?;
+            }
         } else {
             for i in 1..=5i64 {
                 LeagueRepository::insert_tournament_position(league_id as i64, i, i).await?;
             }
         }
 
+        if league.tournament_type_id as i64 == tournament_type::AUCTION_DRAFT {
+            AuctionRepository::insert_auction(
+                league_id as i64,
+                DEFAULT_BUDGET,
+                DEFAULT_BID_SECONDS,
+            )
+            .await?;
+        }
+
         let leagues = LeagueRepository::fetch_user_leagues(&UserLeaguesRequest {
             user_id: league.user_id as i64,
         })
@@ -1167,22 +2313,81 @@ impl LeagueService {
             }
         }
 
-        LeagueRepository::refresh_competition_leaderboard().await
+        LeagueRepository::refresh_competition_leaderboard().await?;
+
+        SurvivorService::process_eliminations(scores.competition_id).await?;
+        SnapshotService::take_snapshots(
+            scores.competition_id,
+            scores.ordinal,
+            snapshot_source::SCORES,
+        )
+        .await
     }
 
     pub async fn join_league(league: &JoinLeague) -> Result<Vec<UserLeaguesResponse>, Error> {
         let is_user_in_league = LeagueRepository::fetch_is_user_in_league(league).await?;
 
         if !is_user_in_league {
-            LeagueRepository::insert_tournament_user(league.tournament_id, league.user_id).await?;
+            Self::add_tournament_user(league).await?;
         }
 
-        let user_leagues = LeagueRepository::fetch_user_leagues(&UserLeaguesRequest {
+        Self::get_user_leagues(&UserLeaguesRequest {
             user_id: league.user_id,
         })
+        .await
+    }
+
+    // Returns false when the league is full and the user was waitlisted instead.
+    async fn add_tournament_user(league: &JoinLeague) -> Result<bool, Error> {
+        let tournament_user_id = LeagueRepository::insert_tournament_user_with_capacity(
+            league.tournament_id,
+            league.user_id,
+        )
         .await?;
 
-        Ok(user_leagues)
+        if tournament_user_id.is_some() {
+            return Ok(true);
+        }
+
+        let waitlist_id = LeagueRepository::fetch_waitlist_id(league).await?;
+
+        if waitlist_id.is_none() {
+            LeagueRepository::insert_waitlist_entry(league.tournament_id, league.user_id).await?;
+        }
+
+        Ok(false)
+    }
+
+    async fn promote_waitlist(tournament_id: i64) -> Result<(), Error> {
+        while let Some(user_id) =
+            LeagueRepository::promote_next_waitlist_entry(tournament_id).await?
+        {
+            let invitation_id =
+                LeagueRepository::fetch_pending_invitation_id(tournament_id, user_id).await?;
+
+            if let Some(invitation_id) = invitation_id {
+                LeagueRepository::update_invitation_status(
+                    invitation_id,
+                    invitation_status::ACCEPTED,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn leave_waitlist(league: &JoinLeague) -> Result<Vec<UserLeaguesResponse>, Error> {
+        let waitlist_id = LeagueRepository::fetch_waitlist_id(league).await?;
+
+        if let Some(waitlist_id) = waitlist_id {
+            LeagueRepository::delete_waitlist_entry(waitlist_id).await?;
+        }
+
+        Self::get_user_leagues(&UserLeaguesRequest {
+            user_id: league.user_id,
+        })
+        .await
     }
 
     pub async fn unlock_workout(competition_id: i64, ordinal: i64) -> Result<(), Error> {
@@ -1194,7 +2399,11 @@ impl LeagueService {
 
     pub async fn lock_workout(competition_id: i64, ordinal: i64) -> Result<(), Error> {
         LeagueRepository::update_event(competition_id, true, ordinal).await?;
-        LeagueRepository::update_workout(competition_id, true, ordinal).await
+        LeagueRepository::update_workout(competition_id, true, ordinal).await?;
+
+        SurvivorService::process_eliminations(competition_id).await?;
+        SnapshotService::take_snapshots(competition_id, ordinal, snapshot_source::LOCK).await?;
+        WaiverService::open_waiver_period(competition_id, ordinal).await
     }
 
     async fn update_competition_gender_adp_new(
@@ -0,0 +1,274 @@
+use crate::data::constants::{pick_error, pick_source, tournament_type};
+use crate::handlers::league::request_models::{SaveShotCallerLineupRequest, SaveTopLineupRequest};
+use crate::handlers::league::response_models::{
+    PickSlotData, ShotCallerPicksBetaResponse, UserLeaguesPicksResponse,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::lineup::LineupRepository;
+use crate::services::league::LeagueService;
+use crate::services::pick_event::PickEventService;
+use crate::services::pick_validation::PickValidationService;
+use sqlx::Error;
+use std::collections::HashSet;
+
+pub struct LineupService;
+
+impl LineupService {
+    // The whole lineup is validated before anything is written, so a bad pick or a short
+    // lineup leaves the member's saved picks untouched.
+    pub async fn save_top_lineup(
+        lineup_request: &SaveTopLineupRequest,
+    ) -> Result<UserLeaguesPicksResponse, Error> {
+        let tournament_user_id = lineup_request.tournament_user_id;
+        let event_status =
+            LeagueRepository::fetch_competition_tournament_status(tournament_user_id).await?;
+
+        if event_status.tournament_type_id != tournament_type::TOP_N {
+            return Err(Error::Protocol(
+                "Full lineups can only be saved for Top-N leagues".to_string(),
+            ));
+        }
+
+        if event_status.is_complete {
+            return Err(Error::Protocol(
+                "Can't update picks for a complete competition".to_string(),
+            ));
+        }
+
+        if event_status.is_active {
+            return Err(Error::Protocol(
+                "Can't update picks for an active competition".to_string(),
+            ));
+        }
+
+        let tournament_id = LeagueRepository::fetch_tournament_member(tournament_user_id)
+            .await?
+            .tournament_id;
+        let metadata = LeagueRepository::fetch_competition(tournament_id).await?;
+        let positions = LeagueRepository::fetch_positions(tournament_id).await?;
+        let competitor_ids: Vec<i64> = lineup_request
+            .picks
+            .iter()
+            .map(|p| p.competitor_id)
+            .collect();
+        let entries =
+            PickValidationService::fetch_competition_entries(tournament_user_id, &competitor_ids)
+                .await?;
+
+        let mut competitors: HashSet<i64> = HashSet::new();
+        let mut slots: HashSet<(i64, i64)> = HashSet::new();
+        let mut ranks: HashSet<(i64, i64)> = HashSet::new();
+
+        for pick in &lineup_request.picks {
+            let entry = &entries[&pick.competitor_id];
+            let gender_id = entry.gender_id;
+
+            PickValidationService::validate_position(
+                &positions,
+                pick.tournament_position_id,
+                entry,
+            )?;
+
+            if pick.rank < 1 || pick.rank > metadata.pick_count {
+                return Err(Error::Protocol(format!(
+                    "Rank must be between 1 and {}",
+                    metadata.pick_count
+                )));
+            }
+
+            if !competitors.insert(pick.competitor_id) {
+                return Err(PickValidationService::reject(
+                    pick_error::DUPLICATE_COMPETITOR,
+                    "Competitor is picked more than once",
+                ));
+            }
+
+            if !slots.insert((gender_id, pick.tournament_position_id)) {
+                return Err(PickValidationService::reject(
+                    pick_error::DUPLICATE_POSITION,
+                    "Position is picked more than once",
+                ));
+            }
+
+            if !ranks.insert((gender_id, pick.rank)) {
+                return Err(PickValidationService::reject(
+                    pick_error::DUPLICATE_RANK,
+                    "Rank is picked more than once",
+                ));
+            }
+        }
+
+        // Distinct slots and ranks, one per position for each gender, cover the whole lineup.
+        if slots.len() != positions.len() * 2 || ranks.len() as i64 != metadata.pick_count * 2 {
+            return Err(Error::Protocol(
+                "Lineup must fill every rank and position for both genders".to_string(),
+            ));
+        }
+
+        let previous: Vec<((i64, i64), PickSlotData, i64)> =
+            LeagueRepository::fetch_user_top_picks(&tournament_user_id)
+                .await?
+                .into_iter()
+                .map(|p| {
+                    (
+                        (p.gender_id, p.tournament_position_id as i64),
+                        PickSlotData {
+                            tournament_user_id,
+                            tournament_position_id: p.tournament_position_id as i64,
+                            workout_id: None,
+                            rank: Some(p.rank),
+                        },
+                        p.competitor_id as i64,
+                    )
+                })
+                .collect();
+        let next: Vec<((i64, i64), PickSlotData, i64)> = lineup_request
+            .picks
+            .iter()
+            .map(|p| {
+                (
+                    (
+                        entries[&p.competitor_id].gender_id,
+                        p.tournament_position_id,
+                    ),
+                    PickSlotData {
+                        tournament_user_id,
+                        tournament_position_id: p.tournament_position_id,
+                        workout_id: None,
+                        rank: Some(p.rank),
+                    },
+                    p.competitor_id,
+                )
+            })
+            .collect();
+
+        let events =
+            PickEventService::get_replacement_events(pick_source::TOP_LINEUP, &previous, &next);
+
+        LineupRepository::replace_top_lineup(tournament_user_id, &lineup_request.picks, &events)
+            .await?;
+
+        LeagueService::get_user_league_picks(&tournament_user_id).await
+    }
+
+    // Picks for workouts that are already active or complete can't be changed, so those
+    // workouts are left out of the replacement. Every open workout needs a full lineup.
+    pub async fn save_shotcaller_lineup(
+        lineup_request: &SaveShotCallerLineupRequest,
+    ) -> Result<ShotCallerPicksBetaResponse, Error> {
+        let tournament_user_id = lineup_request.tournament_user_id;
+        let event_status =
+            LeagueRepository::fetch_competition_tournament_status(tournament_user_id).await?;
+
+        if event_status.tournament_type_id != tournament_type::SHOTCALLER {
+            return Err(Error::Protocol(
+                "Full lineups can only be saved for ShotCaller leagues".to_string(),
+            ));
+        }
+
+        if event_status.is_complete {
+            return Err(Error::Protocol(
+                "Can't update picks for a complete competition".to_string(),
+            ));
+        }
+
+        let tournament_id = LeagueRepository::fetch_tournament_member(tournament_user_id)
+            .await?
+            .tournament_id;
+        let open_workouts: Vec<i64> = LeagueRepository::fetch_workouts_by_tournament(tournament_id)
+            .await?
+            .into_iter()
+            .filter(|w| !w.is_active && !w.is_complete)
+            .map(|w| w.id)
+            .collect();
+        let positions = LeagueRepository::fetch_positions(tournament_id).await?;
+        let competitor_ids: Vec<i64> = lineup_request
+            .picks
+            .iter()
+            .map(|p| p.competitor_id)
+            .collect();
+        let entries =
+            PickValidationService::fetch_competition_entries(tournament_user_id, &competitor_ids)
+                .await?;
+
+        let mut competitors: HashSet<(i64, i64)> = HashSet::new();
+        let mut slots: HashSet<(i64, i64)> = HashSet::new();
+
+        for pick in &lineup_request.picks {
+            if !open_workouts.contains(&pick.workout_id) {
+                return Err(Error::Protocol(
+                    "Can't update picks for an active event".to_string(),
+                ));
+            }
+
+            PickValidationService::validate_position(
+                &positions,
+                pick.tournament_position_id,
+                &entries[&pick.competitor_id],
+            )?;
+
+            if !competitors.insert((pick.workout_id, pick.competitor_id)) {
+                return Err(PickValidationService::reject(
+                    pick_error::DUPLICATE_COMPETITOR,
+                    "Competitor is picked more than once for a workout",
+                ));
+            }
+
+            if !slots.insert((pick.workout_id, pick.tournament_position_id)) {
+                return Err(PickValidationService::reject(
+                    pick_error::DUPLICATE_POSITION,
+                    "Position is picked more than once for a workout",
+                ));
+            }
+        }
+
+        if slots.len() != open_workouts.len() * positions.len() {
+            return Err(Error::Protocol(
+                "Lineup must fill every position for each open workout".to_string(),
+            ));
+        }
+
+        let to_event = |workout_id: i64, tournament_position_id: i64, competitor_id: i64| {
+            (
+                (workout_id, tournament_position_id),
+                PickSlotData {
+                    tournament_user_id,
+                    tournament_position_id,
+                    workout_id: Some(workout_id),
+                    rank: None,
+                },
+                competitor_id,
+            )
+        };
+        let previous: Vec<((i64, i64), PickSlotData, i64)> =
+            LeagueRepository::fetch_shotcaller_picks(tournament_id, &[tournament_user_id], true)
+                .await?
+                .remove(&tournament_user_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|p| open_workouts.contains(&p.workout_id))
+                .map(|p| to_event(p.workout_id, p.tournament_position_id, p.competitor_id))
+                .collect();
+        let next: Vec<((i64, i64), PickSlotData, i64)> = lineup_request
+            .picks
+            .iter()
+            .map(|p| to_event(p.workout_id, p.tournament_position_id, p.competitor_id))
+            .collect();
+
+        let events = PickEventService::get_replacement_events(
+            pick_source::SHOTCALLER_LINEUP,
+            &previous,
+            &next,
+        );
+
+        LineupRepository::replace_shotcaller_lineup(
+            tournament_user_id,
+            &open_workouts,
+            &lineup_request.picks,
+            &events,
+        )
+        .await?;
+
+        LeagueService::get_shot_caller_picks_beta(&tournament_user_id).await
+    }
+}
@@ -1,8 +1,25 @@
 pub mod account;
 pub mod athlete;
-pub mod crossfit;
+pub mod auction;
 pub mod competition;
+pub mod competitor_status;
+pub mod consensus;
+pub mod crossfit;
+pub mod draft;
+pub mod global_leaderboard;
+pub mod hindsight;
 pub mod league;
+pub mod lineup;
 pub mod news;
 pub mod open;
+pub mod pick_event;
+pub mod pick_validation;
+pub mod podium;
+pub mod projection;
 pub mod props;
+pub mod salary;
+pub mod season;
+pub mod simulation;
+pub mod snapshot;
+pub mod survivor;
+pub mod waiver;
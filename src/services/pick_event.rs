@@ -0,0 +1,87 @@
+use crate::data::constants::pick_action;
+use crate::handlers::league::response_models::{PickEventData, PickSlotData, PickTimelineResponse};
+use crate::repositories::pick_event::PickEventRepository;
+use sqlx::Error;
+
+pub struct PickEventService;
+
+impl PickEventService {
+    // Saving the competitor a slot already holds isn't a change, so nothing is recorded.
+    pub async fn record(
+        action: &str,
+        source: &str,
+        slot: &PickSlotData,
+        previous_competitor_id: Option<i64>,
+        competitor_id: Option<i64>,
+    ) -> Result<(), Error> {
+        if previous_competitor_id == competitor_id {
+            return Ok(());
+        }
+
+        PickEventRepository::insert_pick_event(
+            action,
+            source,
+            slot,
+            previous_competitor_id,
+            competitor_id,
+        )
+        .await
+    }
+
+    // Slots are matched by `K`, each one with a new competitor is a save and each previous
+    // slot missing from the new lineup is a delete. The events are returned for the caller to
+    // write alongside the lineup.
+    pub fn get_replacement_events<K: PartialEq>(
+        source: &str,
+        previous: &[(K, PickSlotData, i64)],
+        next: &[(K, PickSlotData, i64)],
+    ) -> Vec<PickEventData> {
+        let event = |action: &str, slot: &PickSlotData, previous_competitor_id, competitor_id| {
+            PickEventData {
+                action: action.to_string(),
+                source: source.to_string(),
+                slot: slot.clone(),
+                previous_competitor_id,
+                competitor_id,
+            }
+        };
+
+        let saves = next.iter().filter_map(|(key, slot, competitor_id)| {
+            let previous_competitor_id = previous
+                .iter()
+                .find(|(k, _, _)| k == key)
+                .map(|(_, _, c)| *c);
+
+            (previous_competitor_id != Some(*competitor_id)).then(|| {
+                event(
+                    pick_action::SAVE,
+                    slot,
+                    previous_competitor_id,
+                    Some(*competitor_id),
+                )
+            })
+        });
+        let deletes = previous
+            .iter()
+            .filter(|(key, _, _)| !next.iter().any(|(k, _, _)| k == key))
+            .map(|(_, slot, competitor_id)| {
+                event(pick_action::DELETE, slot, Some(*competitor_id), None)
+            });
+
+        saves.chain(deletes).collect()
+    }
+
+    pub async fn get_timeline(
+        tournament_id: i64,
+        tournament_user_id: i64,
+    ) -> Result<PickTimelineResponse, Error> {
+        let events =
+            PickEventRepository::fetch_pick_events(tournament_id, tournament_user_id).await?;
+
+        Ok(PickTimelineResponse {
+            tournament_id,
+            tournament_user_id,
+            events,
+        })
+    }
+}
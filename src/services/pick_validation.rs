@@ -0,0 +1,208 @@
+use crate::data::constants::pick_error;
+use crate::handlers::league::request_models::{CreateShotCallerPickRequest, SwapPickRequest};
+use crate::handlers::league::response_models::{CompetitionEntryData, LeaguePosition};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::pick_validation::PickValidationRepository;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub struct PickValidationService;
+
+impl PickValidationService {
+    // Errors lead with a stable code so clients can tell the failures apart.
+    pub(crate) fn reject(code: &str, message: &str) -> Error {
+        Error::Protocol(format!("{}: {}", code, message))
+    }
+
+    pub(crate) async fn fetch_competition_entries(
+        tournament_user_id: i64,
+        competitor_ids: &[i64],
+    ) -> Result<HashMap<i64, CompetitionEntryData>, Error> {
+        let entries =
+            PickValidationRepository::fetch_competition_entries(tournament_user_id, competitor_ids)
+                .await?;
+
+        if competitor_ids.iter().any(|id| !entries.contains_key(id)) {
+            return Err(Self::reject(
+                pick_error::NOT_IN_COMPETITION,
+                "Competitor isn't in this competition",
+            ));
+        }
+
+        Ok(entries)
+    }
+
+    // The position has to belong to the member's league and, when it's restricted, accept
+    // the competitor's division.
+    pub(crate) fn validate_position(
+        positions: &[LeaguePosition],
+        tournament_position_id: i64,
+        entry: &CompetitionEntryData,
+    ) -> Result<(), Error> {
+        let Some(position) = positions
+            .iter()
+            .find(|p| p.position_id == tournament_position_id)
+        else {
+            return Err(Self::reject(
+                pick_error::INVALID_POSITION,
+                "Position doesn't belong to this league",
+            ));
+        };
+
+        if let Some(allowed_positions) = &position.allowed_positions {
+            if !allowed_positions.contains(&entry.position_id.unwrap_or(0)) {
+                return Err(Self::reject(
+                    pick_error::GENDER_NOT_ALLOWED,
+                    "Competitor can't fill this position",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_positions(tournament_user_id: i64) -> Result<Vec<LeaguePosition>, Error> {
+        let member = LeagueRepository::fetch_tournament_member(tournament_user_id).await?;
+
+        LeagueRepository::fetch_positions(member.tournament_id).await
+    }
+
+    // A Top-N pick replaces whatever sits in the same gender and position, so only the
+    // competitor showing up in another slot counts as a duplicate. `replaced_pick_id` is a
+    // pick that is about to be removed and is ignored.
+    pub async fn validate_top_pick(
+        tournament_user_id: i64,
+        competitor_id: i64,
+        tournament_position_id: i64,
+        replaced_pick_id: Option<i64>,
+    ) -> Result<(), Error> {
+        let positions = Self::fetch_positions(tournament_user_id).await?;
+        let entries = Self::fetch_competition_entries(tournament_user_id, &[competitor_id]).await?;
+        let entry = &entries[&competitor_id];
+
+        Self::validate_position(&positions, tournament_position_id, entry)?;
+
+        let picks = LeagueRepository::fetch_user_top_picks(&tournament_user_id).await?;
+
+        if picks.iter().any(|p| {
+            p.competitor_id as i64 == competitor_id
+                && Some(p.id) != replaced_pick_id
+                && (p.gender_id != entry.gender_id
+                    || p.tournament_position_id as i64 != tournament_position_id)
+        }) {
+            return Err(Self::reject(
+                pick_error::DUPLICATE_COMPETITOR,
+                "Competitor is already in the lineup",
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn validate_shotcaller_pick(
+        pick_request: &CreateShotCallerPickRequest,
+    ) -> Result<(), Error> {
+        let member =
+            LeagueRepository::fetch_tournament_member(pick_request.tournament_user_id).await?;
+        let workouts = LeagueRepository::fetch_workouts_by_tournament(member.tournament_id).await?;
+
+        if !workouts.iter().any(|w| w.id == pick_request.workout_id) {
+            return Err(Self::reject(
+                pick_error::INVALID_WORKOUT,
+                "Workout doesn't belong to this competition",
+            ));
+        }
+
+        let positions = LeagueRepository::fetch_positions(member.tournament_id).await?;
+        let entries = Self::fetch_competition_entries(
+            pick_request.tournament_user_id,
+            &[pick_request.competitor_id],
+        )
+        .await?;
+
+        Self::validate_position(
+            &positions,
+            pick_request.tournament_position_id,
+            &entries[&pick_request.competitor_id],
+        )?;
+
+        let position_ids = PickValidationRepository::fetch_workout_competitor_position_ids(
+            pick_request.tournament_user_id,
+            pick_request.workout_id,
+            pick_request.competitor_id,
+        )
+        .await?;
+
+        if position_ids
+            .iter()
+            .any(|id| *id != pick_request.tournament_position_id)
+        {
+            return Err(Self::reject(
+                pick_error::DUPLICATE_COMPETITOR,
+                "Competitor is already picked for this workout",
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Both picks have to be the member's own. A move re-checks the competitor against the
+    // new position, a swap checks each competitor against the other's position.
+    pub async fn validate_swap(pick_request: &SwapPickRequest) -> Result<(), Error> {
+        let mut pick_ids = vec![pick_request.previous_pick_id];
+        pick_ids.extend(pick_request.next_pick.next_pick_id);
+
+        for pick_id in &pick_ids {
+            let (tournament_user_id, _) = LeagueRepository::fetch_pick_scope(*pick_id).await?;
+
+            if tournament_user_id != pick_request.tournament_user_id {
+                return Err(Self::reject(
+                    pick_error::PICK_NOT_OWNED,
+                    "Pick doesn't belong to this member",
+                ));
+            }
+        }
+
+        let previous =
+            LeagueRepository::fetch_pick_competitor(pick_request.previous_pick_id).await?;
+
+        let Some(next_pick_id) = pick_request.next_pick.next_pick_id else {
+            let Some(tournament_position_id) = pick_request.next_pick.tournament_position_id else {
+                return Err(Self::reject(
+                    pick_error::INVALID_POSITION,
+                    "A position is required to move a pick",
+                ));
+            };
+
+            return Self::validate_top_pick(
+                pick_request.tournament_user_id,
+                previous.competitor_id,
+                tournament_position_id,
+                Some(previous.id),
+            )
+            .await;
+        };
+
+        let next = LeagueRepository::fetch_pick_competitor(next_pick_id).await?;
+        let positions = Self::fetch_positions(pick_request.tournament_user_id).await?;
+        let entries = Self::fetch_competition_entries(
+            pick_request.tournament_user_id,
+            &[previous.competitor_id, next.competitor_id],
+        )
+        .await?;
+        let previous_entry = &entries[&previous.competitor_id];
+        let next_entry = &entries[&next.competitor_id];
+
+        if previous_entry.gender_id != next_entry.gender_id {
+            return Err(Self::reject(
+                pick_error::GENDER_NOT_ALLOWED,
+                "Picks of different genders can't be swapped",
+            ));
+        }
+
+        Self::validate_position(&positions, next.tournament_position_id, previous_entry)?;
+        Self::validate_position(&positions, previous.tournament_position_id, next_entry)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::data::constants::podium_points;
+use crate::handlers::league::request_models::CreateShotCallerPickRequest;
+use crate::handlers::league::response_models::{
+    LeaderboardMatchupPodiumResponse, PodiumMatchupDetail, PodiumPick,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::pick_validation::PickValidationRepository;
+use crate::repositories::podium::PodiumRepository;
+use sqlx::Error;
+use tokio::join;
+
+pub struct PodiumService;
+
+impl PodiumService {
+    fn get_matchup_detail(picks: Vec<PodiumPick>) -> PodiumMatchupDetail {
+        PodiumMatchupDetail {
+            points: picks.iter().map(|p| p.points).sum(),
+            exact_picks: picks
+                .iter()
+                .filter(|p| p.points == podium_points::EXACT)
+                .count() as i64,
+            picks,
+        }
+    }
+
+    // A competitor can only fill one step of the same workout's podium.
+    pub async fn validate_pick(pick_request: &CreateShotCallerPickRequest) -> Result<(), Error> {
+        let position_ids = PickValidationRepository::fetch_workout_competitor_position_ids(
+            pick_request.tournament_user_id,
+            pick_request.workout_id,
+            pick_request.competitor_id,
+        )
+        .await?;
+
+        if position_ids
+            .iter()
+            .any(|id| *id != pick_request.tournament_position_id)
+        {
+            return Err(Error::Protocol(
+                "Competitor is already on this podium".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_podium_leaderboard_matchup(
+        tournament_id: i64,
+        user_id: i64,
+        competitor_id: i64,
+    ) -> Result<LeaderboardMatchupPodiumResponse, Error> {
+        let res = join!(
+            LeagueRepository::fetch_workouts_by_tournament(tournament_id),
+            PodiumRepository::fetch_podium_picks(tournament_id, user_id),
+            PodiumRepository::fetch_podium_picks(tournament_id, competitor_id),
+        );
+
+        Ok(LeaderboardMatchupPodiumResponse {
+            workouts: res.0?,
+            user_matchup: Self::get_matchup_detail(res.1?),
+            competitor_matchup: Self::get_matchup_detail(res.2?),
+        })
+    }
+}
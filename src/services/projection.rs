@@ -0,0 +1,146 @@
+use crate::data::constants::tournament_type;
+use crate::handlers::league::response_models::{
+    LeaderboardTournamentUserData, ProjectedCompetitor, ProjectedStandingEntry,
+    ProjectedStandingsResponse,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::services::league::LeagueService;
+use crate::services::simulation::SimulationService;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub struct ProjectionService;
+
+impl ProjectionService {
+    // Each competitor is assumed to keep scoring at their current average for the remaining
+    // workouts. Members start from their leaderboard points, so props and bonuses carry over.
+    // Top-N leagues are re-scored against the projected placements. ShotCaller picks for
+    // upcoming workouts are still hidden, so members are projected at their points per
+    // completed workout instead.
+    pub async fn get_projected_standings(
+        tournament_id: i64,
+    ) -> Result<ProjectedStandingsResponse, Error> {
+        let (metadata, men_leaderboard, women_leaderboard) =
+            LeagueService::fetch_matchup_leaderboards(tournament_id).await?;
+        let tournament_type_id = metadata.tournament_type_id as i64;
+
+        if tournament_type_id != tournament_type::TOP_N
+            && tournament_type_id != tournament_type::SHOTCALLER
+        {
+            return Err(Error::Protocol(
+                "Projected standings are only available for Top-N and ShotCaller leagues"
+                    .to_string(),
+            ));
+        }
+
+        let workouts = LeagueRepository::fetch_workouts_by_tournament(tournament_id).await?;
+        let leaderboard = LeagueService::get_league_leaderboard_new(&tournament_id).await?;
+
+        let remaining_events = workouts.iter().filter(|w| !w.is_complete).count() as i64;
+        let completed_events = workouts.len() as i64 - remaining_events;
+        let forms =
+            SimulationService::get_competitor_forms(&[&men_leaderboard, &women_leaderboard]);
+
+        let projected_totals: Vec<f64> = forms
+            .iter()
+            .map(|f| {
+                if f.is_withdrawn {
+                    f.points
+                } else {
+                    f.points + f.mean * remaining_events as f64
+                }
+            })
+            .collect();
+
+        let current_placements: HashMap<i64, i64> = men_leaderboard
+            .values()
+            .chain(women_leaderboard.values())
+            .map(|c| (c.competitor_id, c.placement))
+            .collect();
+        let projected_placements = SimulationService::get_placements(&forms, &projected_totals);
+
+        let mut competitors: Vec<ProjectedCompetitor> = forms
+            .iter()
+            .zip(&projected_totals)
+            .map(|(f, projected_points)| {
+                let competitor = men_leaderboard
+                    .get(&f.competitor_id)
+                    .or(women_leaderboard.get(&f.competitor_id))
+                    .unwrap();
+
+                ProjectedCompetitor {
+                    competitor_id: f.competitor_id,
+                    first_name: competitor.first_name.clone(),
+                    last_name: competitor.last_name.clone(),
+                    gender_id: f.gender_id,
+                    current_points: f.points,
+                    current_placement: competitor.placement,
+                    projected_points: *projected_points,
+                    projected_placement: projected_placements[&f.competitor_id],
+                }
+            })
+            .collect();
+
+        competitors.sort_by_key(|c| (c.gender_id, c.projected_placement));
+
+        let projected_points: Vec<f64> = if tournament_type_id == tournament_type::TOP_N {
+            let members: HashMap<u64, LeaderboardTournamentUserData> =
+                LeagueRepository::fetch_tournament_users(tournament_id)
+                    .await?
+                    .into_iter()
+                    .map(|m| (m.tournament_user_id, m))
+                    .collect();
+
+            leaderboard
+                .leaderboard
+                .iter()
+                .map(|entry| match members.get(&entry.tournament_user_id) {
+                    Some(m) => {
+                        entry.points - SimulationService::get_top_n_points(m, &current_placements)
+                            + SimulationService::get_top_n_points(m, &projected_placements)
+                    }
+                    None => entry.points,
+                })
+                .collect()
+        } else {
+            leaderboard
+                .leaderboard
+                .iter()
+                .map(|entry| {
+                    if completed_events == 0 {
+                        entry.points
+                    } else {
+                        entry.points
+                            + entry.points / completed_events as f64 * remaining_events as f64
+                    }
+                })
+                .collect()
+        };
+        let projected_ranks = SimulationService::get_ranks(&projected_points);
+
+        let mut standings: Vec<ProjectedStandingEntry> = leaderboard
+            .leaderboard
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| ProjectedStandingEntry {
+                tournament_user_id: entry.tournament_user_id as i64,
+                display_name: entry.display_name.clone(),
+                avatar: entry.avatar.clone(),
+                current_points: entry.points,
+                current_rank: entry.ordinal,
+                projected_points: projected_points[i],
+                projected_rank: projected_ranks[i],
+                movement: entry.ordinal - projected_ranks[i],
+            })
+            .collect();
+
+        standings.sort_by_key(|s| s.projected_rank);
+
+        Ok(ProjectedStandingsResponse {
+            tournament_id,
+            remaining_events,
+            competitors,
+            standings,
+        })
+    }
+}
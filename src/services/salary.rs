@@ -0,0 +1,241 @@
+use crate::data::constants::{pick_action, pick_source, tournament_type};
+use crate::handlers::league::request_models::CreateSalaryPickRequest;
+use crate::handlers::league::response_models::{
+    CompetitorSalaryResponse, PickSlotData, SalaryData, SalaryLineupPick, SalaryLineupResponse,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::salary::SalaryRepository;
+use crate::services::pick_event::PickEventService;
+use sqlx::Error;
+use std::cmp::Reverse;
+
+pub(crate) const SALARY_CAP: i64 = 50000;
+const MIN_SALARY: i64 = 4000;
+const MAX_SALARY: i64 = 12000;
+// Share of the salary driven by ADP, the remainder comes from pick percentage.
+const ADP_WEIGHT: f64 = 0.75;
+
+pub struct SalaryService;
+
+impl SalaryService {
+    // Competitors are priced within their gender: the best ADP and the most picked earn the top salary.
+    // Withdrawn competitors keep their price so lineups that already hold them still count it.
+    fn calculate_salaries(athletes: Vec<SalaryData>) -> Vec<CompetitorSalaryResponse> {
+        let mut salaries: Vec<CompetitorSalaryResponse> = vec![];
+
+        for gender_id in [1, 2] {
+            let mut gender_athletes: Vec<&SalaryData> = athletes
+                .iter()
+                .filter(|a| a.gender_id == gender_id)
+                .collect();
+
+            // Competitors without an ADP are priced last.
+            gender_athletes.sort_by(|a, b| {
+                let a_adp = if a.adp > 0.0 { a.adp } else { f64::MAX };
+                let b_adp = if b.adp > 0.0 { b.adp } else { f64::MAX };
+
+                a_adp.total_cmp(&b_adp)
+            });
+
+            let count = gender_athletes.len();
+            let max_pick_percentage = gender_athletes
+                .iter()
+                .map(|a| a.pick_percentage)
+                .fold(0.0, f64::max);
+
+            for (i, a) in gender_athletes.iter().enumerate() {
+                let adp_score = if count > 1 {
+                    1.0 - i as f64 / (count - 1) as f64
+                } else {
+                    1.0
+                };
+                let pick_score = if max_pick_percentage > 0.0 {
+                    a.pick_percentage / max_pick_percentage
+                } else {
+                    0.0
+                };
+                let value = ADP_WEIGHT * adp_score + (1.0 - ADP_WEIGHT) * pick_score;
+                let salary = MIN_SALARY as f64 + value * (MAX_SALARY - MIN_SALARY) as f64;
+
+                salaries.push(CompetitorSalaryResponse {
+                    competitor_id: a.competitor_id,
+                    first_name: a.first_name.clone(),
+                    last_name: a.last_name.clone(),
+                    gender_id: a.gender_id,
+                    position_id: a.position_id,
+                    adp: a.adp,
+                    pick_percentage: a.pick_percentage,
+                    salary: (salary / 100.0).round() as i64 * 100,
+                    is_withdrawn: a.is_withdrawn,
+                });
+            }
+        }
+
+        salaries.sort_by_key(|s| Reverse(s.salary));
+        salaries
+    }
+
+    pub async fn get_salaries(competition_id: i64) -> Result<Vec<CompetitorSalaryResponse>, Error> {
+        let athletes = SalaryRepository::fetch_salary_data(competition_id).await?;
+
+        Ok(Self::calculate_salaries(athletes))
+    }
+
+    async fn get_competition_salaries(
+        tournament_user_id: i64,
+    ) -> Result<Vec<CompetitorSalaryResponse>, Error> {
+        let member = LeagueRepository::fetch_tournament_member(tournament_user_id).await?;
+        let metadata = LeagueRepository::fetch_competition(member.tournament_id).await?;
+
+        Self::get_salaries(metadata.competition_id as i64).await
+    }
+
+    pub async fn get_lineup(tournament_user_id: i64) -> Result<SalaryLineupResponse, Error> {
+        let salaries = Self::get_competition_salaries(tournament_user_id).await?;
+        let lineup = SalaryRepository::fetch_lineup(tournament_user_id).await?;
+
+        let picks: Vec<SalaryLineupPick> = lineup
+            .iter()
+            .filter_map(|p| {
+                salaries
+                    .iter()
+                    .find(|s| s.competitor_id == p.competitor_id)
+                    .map(|s| SalaryLineupPick {
+                        id: p.id,
+                        tournament_position_id: p.tournament_position_id,
+                        competitor_id: p.competitor_id,
+                        first_name: s.first_name.clone(),
+                        last_name: s.last_name.clone(),
+                        salary: s.salary,
+                    })
+            })
+            .collect();
+
+        let salary_used = picks.iter().map(|p| p.salary).sum();
+
+        Ok(SalaryLineupResponse {
+            tournament_user_id,
+            salary_cap: SALARY_CAP,
+            salary_used,
+            salary_remaining: SALARY_CAP - salary_used,
+            picks,
+        })
+    }
+
+    pub async fn save_pick(
+        pick_request: &CreateSalaryPickRequest,
+    ) -> Result<SalaryLineupResponse, Error> {
+        let event_status =
+            LeagueRepository::fetch_competition_tournament_status(pick_request.tournament_user_id)
+                .await?;
+
+        if event_status.tournament_type_id != tournament_type::SALARY_CAP {
+            return Err(Error::Protocol(
+                "Salary picks are only used in salary cap leagues".to_string(),
+            ));
+        }
+
+        if event_status.is_complete {
+            return Err(Error::Protocol(
+                "Can't update picks for a complete competition".to_string(),
+            ));
+        }
+
+        if event_status.is_active {
+            return Err(Error::Protocol(
+                "Can't update picks for an active competition".to_string(),
+            ));
+        }
+
+        let member =
+            LeagueRepository::fetch_tournament_member(pick_request.tournament_user_id).await?;
+        let positions = LeagueRepository::fetch_positions(member.tournament_id).await?;
+        let Some(position) = positions
+            .iter()
+            .find(|p| p.position_id == pick_request.tournament_position_id)
+        else {
+            return Err(Error::Protocol("Invalid tournament position".to_string()));
+        };
+
+        let salaries = Self::get_competition_salaries(pick_request.tournament_user_id).await?;
+        let Some(athlete) = salaries
+            .iter()
+            .find(|s| s.competitor_id == pick_request.competitor_id)
+        else {
+            return Err(Error::Protocol(
+                "Competitor isn't available in this competition".to_string(),
+            ));
+        };
+
+        if athlete.is_withdrawn {
+            return Err(Error::Protocol("Competitor has withdrawn".to_string()));
+        }
+
+        if let Some(allowed_positions) = &position.allowed_positions {
+            if !allowed_positions.contains(&athlete.position_id.unwrap_or(0)) {
+                return Err(Error::Protocol(
+                    "Competitor can't fill this position".to_string(),
+                ));
+            }
+        }
+
+        let lineup = SalaryRepository::fetch_lineup(pick_request.tournament_user_id).await?;
+
+        if lineup.iter().any(|p| {
+            p.competitor_id == pick_request.competitor_id
+                && p.tournament_position_id != pick_request.tournament_position_id
+        }) {
+            return Err(Error::Protocol(
+                "Competitor is already in the lineup".to_string(),
+            ));
+        }
+
+        let salary_used: i64 = lineup
+            .iter()
+            .filter(|p| p.tournament_position_id != pick_request.tournament_position_id)
+            .filter_map(|p| salaries.iter().find(|s| s.competitor_id == p.competitor_id))
+            .map(|s| s.salary)
+            .sum();
+
+        if salary_used + athlete.salary > SALARY_CAP {
+            return Err(Error::Protocol(format!(
+                "Lineup is over the salary cap of {}",
+                SALARY_CAP
+            )));
+        }
+
+        let previous_pick = lineup
+            .iter()
+            .find(|p| p.tournament_position_id == pick_request.tournament_position_id);
+
+        if let Some(previous_pick) = previous_pick {
+            LeagueRepository::delete_user_league_pick(previous_pick.id).await?;
+        }
+
+        LeagueRepository::insert_top_user_league_pick(
+            pick_request.tournament_user_id,
+            pick_request.competitor_id,
+            position.ordinal,
+            pick_request.tournament_position_id,
+            None,
+            false,
+        )
+        .await?;
+
+        PickEventService::record(
+            pick_action::SAVE,
+            pick_source::SALARY,
+            &PickSlotData {
+                tournament_user_id: pick_request.tournament_user_id,
+                tournament_position_id: pick_request.tournament_position_id,
+                workout_id: None,
+                rank: Some(position.ordinal),
+            },
+            previous_pick.map(|p| p.competitor_id),
+            Some(pick_request.competitor_id),
+        )
+        .await?;
+
+        Self::get_lineup(pick_request.tournament_user_id).await
+    }
+}
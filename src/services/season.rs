@@ -0,0 +1,162 @@
+use crate::data::models::season::Season;
+use crate::handlers::season::request_models::{
+    CreateSeasonRequest, SeasonCommissionerRequest, SeasonTournamentPathRequest,
+    SeasonTournamentRequest,
+};
+use crate::handlers::season::response_models::{
+    SeasonCompetitionResponse, SeasonResponse, SeasonResult, SeasonStandingEntry,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::season::SeasonRepository;
+use crate::services::league::LeagueService;
+use sqlx::Error;
+use std::collections::HashMap;
+
+pub struct SeasonService;
+
+impl SeasonService {
+    pub async fn create_season(req: &CreateSeasonRequest) -> Result<Season, Error> {
+        let season_id = SeasonRepository::insert_season(&req.name, req.user_id).await?;
+
+        SeasonRepository::fetch_season(season_id).await
+    }
+
+    // Standings sum each member's weighted points across the season's leagues. Members with
+    // several entries in one league are credited with their best entry.
+    pub async fn get_season(season_id: i64) -> Result<SeasonResponse, Error> {
+        let season = SeasonRepository::fetch_season(season_id).await?;
+        let season_tournaments = SeasonRepository::fetch_season_tournaments(season_id).await?;
+        let members = SeasonRepository::fetch_season_members(season_id).await?;
+
+        let member_users: HashMap<i64, i64> = members
+            .iter()
+            .map(|m| (m.tournament_user_id, m.user_id))
+            .collect();
+
+        let mut competitions: Vec<SeasonCompetitionResponse> = vec![];
+        let mut standings: Vec<SeasonStandingEntry> = vec![];
+
+        for season_tournament in &season_tournaments {
+            let leaderboard =
+                LeagueService::get_league_leaderboard_new(&season_tournament.tournament_id).await?;
+
+            competitions.push(SeasonCompetitionResponse {
+                tournament_id: season_tournament.tournament_id,
+                tournament: leaderboard.tournament,
+                competition_id: season_tournament.competition_id,
+                competition: leaderboard.competition,
+                weight: season_tournament.weight,
+            });
+
+            for entry in leaderboard.leaderboard {
+                let Some(user_id) = member_users.get(&(entry.tournament_user_id as i64)) else {
+                    continue;
+                };
+
+                let result = SeasonResult {
+                    tournament_id: season_tournament.tournament_id,
+                    points: entry.points,
+                    weighted_points: entry.points * season_tournament.weight,
+                    rank: entry.ordinal,
+                };
+
+                let Some(standing) = standings.iter_mut().find(|s| s.user_id == *user_id) else {
+                    standings.push(SeasonStandingEntry {
+                        user_id: *user_id,
+                        display_name: entry.display_name,
+                        avatar: entry.avatar,
+                        points: 0.0,
+                        rank: 0,
+                        results: vec![result],
+                    });
+                    continue;
+                };
+
+                match standing
+                    .results
+                    .iter_mut()
+                    .find(|r| r.tournament_id == season_tournament.tournament_id)
+                {
+                    Some(existing) if existing.points < result.points => *existing = result,
+                    Some(_) => {}
+                    None => standing.results.push(result),
+                }
+            }
+        }
+
+        for standing in standings.iter_mut() {
+            standing.points = standing.results.iter().map(|r| r.weighted_points).sum();
+        }
+
+        standings.sort_by(|a, b| b.points.total_cmp(&a.points));
+
+        for i in 0..standings.len() {
+            standings[i].rank = if i > 0 && standings[i].points == standings[i - 1].points {
+                standings[i - 1].rank
+            } else {
+                i as i64 + 1
+            };
+        }
+
+        Ok(SeasonResponse {
+            season_id: season.id,
+            name: season.name,
+            commissioner_id: season.commissioner_id,
+            competitions,
+            standings,
+        })
+    }
+
+    async fn validate_commissioner(season_id: i64, user_id: i64) -> Result<(), Error> {
+        let season = SeasonRepository::fetch_season(season_id).await?;
+
+        if season.commissioner_id != user_id {
+            return Err(Error::Protocol(
+                "Only the commissioner can update a season".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn add_season_tournament(
+        path: &SeasonCommissionerRequest,
+        req: &SeasonTournamentRequest,
+    ) -> Result<SeasonResponse, Error> {
+        Self::validate_commissioner(path.season_id, path.user_id).await?;
+
+        let tournament = LeagueRepository::fetch_tournament(req.tournament_id).await?;
+
+        if tournament.commissioner_id as i64 != path.user_id {
+            return Err(Error::Protocol(
+                "Only the league commissioner can add a league to a season".to_string(),
+            ));
+        }
+
+        let season_tournaments = SeasonRepository::fetch_season_tournaments(path.season_id).await?;
+
+        if season_tournaments.iter().any(|st| {
+            st.competition_id == tournament.competition_id as i64
+                && st.tournament_id != req.tournament_id
+        }) {
+            return Err(Error::Protocol(
+                "Season already has a league for this competition".to_string(),
+            ));
+        }
+
+        SeasonRepository::upsert_season_tournament(path.season_id, req.tournament_id, req.weight)
+            .await?;
+
+        Self::get_season(path.season_id).await
+    }
+
+    pub async fn remove_season_tournament(
+        req: &SeasonTournamentPathRequest,
+    ) -> Result<SeasonResponse, Error> {
+        Self::validate_commissioner(req.season_id, req.user_id).await?;
+
+        SeasonRepository::delete_season_tournament(req.season_id, req.tournament_id).await?;
+
+        Self::get_season(req.season_id).await
+    }
+}
@@ -0,0 +1,410 @@
+use crate::data::constants::{captain, tournament_type};
+use crate::handlers::league::request_models::WinProbabilityRequest;
+use crate::handlers::league::response_models::{
+    CompetitionLeaderboardResponse, CompetitorProjection, LeaderboardPicks,
+    LeaderboardTournamentUserData, MemberWinProbability, WinProbabilityResponse,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::services::league::LeagueService;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sqlx::Error;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+const DEFAULT_SIMULATIONS: i64 = 1000;
+const DEFAULT_SEED: u64 = 1;
+const MAX_EVENT_POINTS: f64 = 100.0;
+
+// A competitor's scoring form, taken from the event points they've posted so far.
+pub(crate) struct CompetitorForm {
+    pub competitor_id: i64,
+    pub gender_id: i64,
+    pub points: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub is_withdrawn: bool,
+}
+
+// Running totals across every simulation, indexed like the forms and members they came from.
+struct SimulationResult {
+    placement_counts: Vec<Vec<i64>>,
+    member_points: Vec<f64>,
+    member_wins: Vec<f64>,
+    member_top_3: Vec<f64>,
+}
+
+pub struct SimulationService;
+
+impl SimulationService {
+    fn get_mean_std_dev(finishes: &[f64]) -> (f64, f64) {
+        let mean = finishes.iter().sum::<f64>() / finishes.len() as f64;
+        let variance =
+            finishes.iter().map(|f| (f - mean).powi(2)).sum::<f64>() / finishes.len() as f64;
+
+        (mean, variance.sqrt())
+    }
+
+    // Competitors with fewer than two finishes fall back to their gender's field-wide form.
+    // Forms are sorted by competitor so a seeded simulation always draws in the same order.
+    pub(crate) fn get_competitor_forms(
+        leaderboards: &[&HashMap<i64, CompetitionLeaderboardResponse>],
+    ) -> Vec<CompetitorForm> {
+        let mut forms: Vec<CompetitorForm> = vec![];
+
+        for leaderboard in leaderboards {
+            let field_finishes: Vec<f64> = leaderboard
+                .values()
+                .flat_map(|c| c.finishes.clone())
+                .collect();
+            let field_form = if field_finishes.is_empty() {
+                (0.0, 0.0)
+            } else {
+                Self::get_mean_std_dev(&field_finishes)
+            };
+
+            forms.extend(leaderboard.values().map(|c| {
+                let (mean, std_dev) = if c.finishes.len() < 2 {
+                    field_form
+                } else {
+                    Self::get_mean_std_dev(&c.finishes)
+                };
+
+                CompetitorForm {
+                    competitor_id: c.competitor_id,
+                    gender_id: c.gender_id,
+                    points: c.points,
+                    mean,
+                    std_dev,
+                    is_withdrawn: c.is_withdrawn,
+                }
+            }));
+        }
+
+        forms.sort_by_key(|f| f.competitor_id);
+        forms
+    }
+
+    // Competition ranking within each gender, highest points first.
+    pub(crate) fn get_placements(forms: &[CompetitorForm], totals: &[f64]) -> HashMap<i64, i64> {
+        forms
+            .iter()
+            .enumerate()
+            .map(|(i, form)| {
+                let placement = forms
+                    .iter()
+                    .zip(totals)
+                    .filter(|(f, total)| f.gender_id == form.gender_id && **total > totals[i])
+                    .count() as i64
+                    + 1;
+
+                (form.competitor_id, placement)
+            })
+            .collect()
+    }
+
+    // Mirrors the Top-N matchup scoring: 10 - |predicted - actual|, floored at zero.
+    pub(crate) fn get_top_n_points(
+        member: &LeaderboardTournamentUserData,
+        placements: &HashMap<i64, i64>,
+    ) -> f64 {
+        member
+            .men_competitor_ids
+            .iter()
+            .chain(member.women_competitor_ids.iter())
+            .map(|p: &LeaderboardPicks| {
+                let placement = placements.get(&p.competitor_id).copied().unwrap_or(0);
+
+                if placement == 0 {
+                    return 0.0;
+                }
+
+                let points = (10 - (p.rank - placement).abs()).max(0) as f64;
+
+                if p.is_captain {
+                    points * captain::MULTIPLIER
+                } else {
+                    points
+                }
+            })
+            .sum()
+    }
+
+    pub(crate) fn get_ranks(points: &[f64]) -> Vec<i64> {
+        points
+            .iter()
+            .map(|p| points.iter().filter(|o| *o > p).count() as i64 + 1)
+            .collect()
+    }
+
+    // Each member's share of the top `places` finishing spots. Members tied across the cut-off
+    // split the spots left between them, so the shares always add up to `places`.
+    pub(crate) fn get_place_shares(ranks: &[i64], places: i64) -> Vec<f64> {
+        ranks
+            .iter()
+            .map(|rank| {
+                let tied = ranks.iter().filter(|r| *r == rank).count() as i64;
+
+                (places - rank + 1).clamp(0, tied) as f64 / tied as f64
+            })
+            .collect()
+    }
+
+    fn sample_event_points(rng: &mut StdRng, form: &CompetitorForm) -> f64 {
+        // Box-Muller transform for a normally distributed event score.
+        let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+        let u2: f64 = rng.gen();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+
+        (form.mean + form.std_dev * z).clamp(0.0, MAX_EVENT_POINTS)
+    }
+
+    // Plays out the remaining events `simulations` times. Withdrawn competitors keep their
+    // current points, and members tied for a spot share the win and top 3 credit.
+    fn simulate(
+        forms: &[CompetitorForm],
+        members: &[LeaderboardTournamentUserData],
+        remaining_events: i64,
+        simulations: i64,
+        seed: u64,
+    ) -> SimulationResult {
+        let mut result = SimulationResult {
+            placement_counts: forms
+                .iter()
+                .map(|f| vec![0; forms.iter().filter(|o| o.gender_id == f.gender_id).count()])
+                .collect(),
+            member_points: vec![0.0; members.len()],
+            member_wins: vec![0.0; members.len()],
+            member_top_3: vec![0.0; members.len()],
+        };
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        for _ in 0..simulations {
+            let totals: Vec<f64> = forms
+                .iter()
+                .map(|f| {
+                    if f.is_withdrawn {
+                        return f.points;
+                    }
+
+                    f.points
+                        + (0..remaining_events)
+                            .map(|_| Self::sample_event_points(&mut rng, f))
+                            .sum::<f64>()
+                })
+                .collect();
+
+            let placements = Self::get_placements(forms, &totals);
+
+            for (i, form) in forms.iter().enumerate() {
+                result.placement_counts[i][placements[&form.competitor_id] as usize - 1] += 1;
+            }
+
+            let points: Vec<f64> = members
+                .iter()
+                .map(|m| Self::get_top_n_points(m, &placements))
+                .collect();
+            let ranks = Self::get_ranks(&points);
+            let wins = Self::get_place_shares(&ranks, 1);
+            let top_3 = Self::get_place_shares(&ranks, 3);
+
+            for i in 0..members.len() {
+                result.member_points[i] += points[i];
+                result.member_wins[i] += wins[i];
+                result.member_top_3[i] += top_3[i];
+            }
+        }
+
+        result
+    }
+
+    pub async fn get_win_probabilities(
+        tournament_id: i64,
+        req: &WinProbabilityRequest,
+    ) -> Result<WinProbabilityResponse, Error> {
+        let (metadata, men_leaderboard, women_leaderboard) =
+            LeagueService::fetch_matchup_leaderboards(tournament_id).await?;
+
+        if metadata.tournament_type_id as i64 != tournament_type::TOP_N {
+            return Err(Error::Protocol(
+                "Win probabilities are only available for Top-N leagues".to_string(),
+            ));
+        }
+
+        let simulations = req.simulations.unwrap_or(DEFAULT_SIMULATIONS);
+        let seed = req.seed.unwrap_or(DEFAULT_SEED);
+
+        let workouts = LeagueRepository::fetch_workouts_by_tournament(tournament_id).await?;
+        let members = LeagueRepository::fetch_tournament_users(tournament_id).await?;
+
+        let remaining_events = workouts.iter().filter(|w| !w.is_complete).count() as i64;
+        let forms = Self::get_competitor_forms(&[&men_leaderboard, &women_leaderboard]);
+
+        let current_placements: HashMap<i64, i64> = men_leaderboard
+            .values()
+            .chain(women_leaderboard.values())
+            .map(|c| (c.competitor_id, c.placement))
+            .collect();
+
+        // The simulations are CPU bound, so they run on the blocking pool instead of holding
+        // up the request's worker.
+        let (forms, members, simulation) = tokio::task::spawn_blocking(move || {
+            let simulation = Self::simulate(&forms, &members, remaining_events, simulations, seed);
+
+            (forms, members, simulation)
+        })
+        .await
+        .map_err(|e| Error::Protocol(e.to_string()))?;
+
+        let leaderboard = |gender_id: i64| {
+            if gender_id == 1 {
+                &men_leaderboard
+            } else {
+                &women_leaderboard
+            }
+        };
+
+        let competitors = forms
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let competitor = &leaderboard(f.gender_id)[&f.competitor_id];
+                let placement_probabilities: Vec<f64> = simulation.placement_counts[i]
+                    .iter()
+                    .map(|c| *c as f64 / simulations as f64)
+                    .collect();
+
+                CompetitorProjection {
+                    competitor_id: f.competitor_id,
+                    first_name: competitor.first_name.clone(),
+                    last_name: competitor.last_name.clone(),
+                    gender_id: f.gender_id,
+                    current_placement: current_placements[&f.competitor_id],
+                    expected_placement: placement_probabilities
+                        .iter()
+                        .enumerate()
+                        .map(|(p, probability)| (p + 1) as f64 * probability)
+                        .sum(),
+                    placement_probabilities,
+                }
+            })
+            .collect();
+
+        let members = members
+            .iter()
+            .enumerate()
+            .map(|(i, m)| MemberWinProbability {
+                tournament_user_id: m.tournament_user_id as i64,
+                display_name: m.display_name.clone(),
+                avatar: m.avatar.clone(),
+                current_points: Self::get_top_n_points(m, &current_placements),
+                expected_points: simulation.member_points[i] / simulations as f64,
+                win_probability: simulation.member_wins[i] / simulations as f64,
+                top_3_probability: simulation.member_top_3[i] / simulations as f64,
+            })
+            .collect();
+
+        Ok(WinProbabilityResponse {
+            tournament_id,
+            simulations,
+            seed,
+            remaining_events,
+            competitors,
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form(competitor_id: i64, points: f64, mean: f64, std_dev: f64) -> CompetitorForm {
+        CompetitorForm {
+            competitor_id,
+            gender_id: 1,
+            points,
+            mean,
+            std_dev,
+            is_withdrawn: false,
+        }
+    }
+
+    fn member(tournament_user_id: u64, picks: &[(i64, i64)]) -> LeaderboardTournamentUserData {
+        LeaderboardTournamentUserData {
+            tournament_user_id,
+            display_name: format!("member {}", tournament_user_id),
+            avatar: String::new(),
+            men_competitor_ids: picks
+                .iter()
+                .map(|(competitor_id, rank)| LeaderboardPicks {
+                    competitor_id: *competitor_id,
+                    rank: *rank,
+                    is_captain: false,
+                })
+                .collect(),
+            women_competitor_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn place_shares_split_ties() {
+        assert_eq!(
+            SimulationService::get_place_shares(&[1, 1, 3], 1),
+            vec![0.5, 0.5, 0.0]
+        );
+        assert_eq!(
+            SimulationService::get_place_shares(&[1, 2, 2, 2], 3),
+            vec![1.0, 2.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0]
+        );
+    }
+
+    #[test]
+    fn tied_members_share_the_win() {
+        let forms = vec![form(1, 50.0, 0.0, 0.0), form(2, 40.0, 0.0, 0.0)];
+        let members = vec![member(1, &[(1, 1), (2, 2)]), member(2, &[(1, 1), (2, 2)])];
+
+        let result = SimulationService::simulate(&forms, &members, 1, 10, DEFAULT_SEED);
+
+        assert_eq!(result.placement_counts, vec![vec![10, 0], vec![0, 10]]);
+        assert_eq!(result.member_points, vec![200.0, 200.0]);
+        assert_eq!(result.member_wins, vec![5.0, 5.0]);
+        assert_eq!(result.member_top_3, vec![10.0, 10.0]);
+    }
+
+    #[test]
+    fn seeded_simulation_is_reproducible() {
+        let forms = vec![
+            form(1, 100.0, 50.0, 20.0),
+            form(2, 90.0, 60.0, 20.0),
+            form(3, 80.0, 70.0, 20.0),
+        ];
+        let members = vec![
+            member(1, &[(1, 1), (2, 2), (3, 3)]),
+            member(2, &[(3, 1), (2, 2), (1, 3)]),
+            member(3, &[(2, 1), (1, 2), (3, 3)]),
+            member(4, &[(3, 1), (1, 2), (2, 3)]),
+        ];
+
+        let result = SimulationService::simulate(&forms, &members, 2, 20, DEFAULT_SEED);
+        let per_simulation = |totals: &[f64]| totals.iter().map(|t| t / 20.0).collect::<Vec<f64>>();
+
+        assert_eq!(
+            result.placement_counts,
+            vec![vec![4, 5, 11], vec![4, 9, 7], vec![12, 6, 2]]
+        );
+        assert_eq!(
+            per_simulation(&result.member_points),
+            vec![26.6, 28.3, 26.6, 27.9]
+        );
+        assert_eq!(
+            per_simulation(&result.member_wins),
+            vec![0.15, 0.45, 0.1, 0.3]
+        );
+        assert_eq!(
+            per_simulation(&result.member_top_3),
+            vec![0.6, 0.9, 0.65, 0.85]
+        );
+    }
+}
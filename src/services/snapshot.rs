@@ -0,0 +1,71 @@
+use crate::handlers::league::response_models::{
+    StandingsHistoryEntry, StandingsHistoryResponse, StandingsSnapshot,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::snapshot::SnapshotRepository;
+use crate::services::league::LeagueService;
+use sqlx::Error;
+
+pub struct SnapshotService;
+
+impl SnapshotService {
+    // Stores the current standings of every league in the competition.
+    pub async fn take_snapshots(
+        competition_id: i64,
+        ordinal: i64,
+        source: &str,
+    ) -> Result<(), Error> {
+        let tournament_ids =
+            SnapshotRepository::fetch_competition_tournament_ids(competition_id).await?;
+
+        for tournament_id in tournament_ids {
+            let leaderboard = LeagueService::get_league_leaderboard_new(&tournament_id).await?;
+
+            if leaderboard.leaderboard.is_empty() {
+                continue;
+            }
+
+            SnapshotRepository::insert_leaderboard_snapshot(
+                tournament_id,
+                ordinal,
+                source,
+                &leaderboard.leaderboard,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_standings_history(
+        tournament_id: i64,
+    ) -> Result<StandingsHistoryResponse, Error> {
+        let tournament_users = LeagueRepository::fetch_tournament_users(tournament_id).await?;
+        let snapshots = SnapshotRepository::fetch_leaderboard_snapshots(tournament_id).await?;
+
+        let standings = tournament_users
+            .into_iter()
+            .map(|tu| StandingsHistoryEntry {
+                tournament_user_id: tu.tournament_user_id as i64,
+                display_name: tu.display_name,
+                avatar: tu.avatar,
+                history: snapshots
+                    .iter()
+                    .filter(|s| s.tournament_user_id == tu.tournament_user_id as i64)
+                    .map(|s| StandingsSnapshot {
+                        ordinal: s.ordinal,
+                        source: s.source.clone(),
+                        points: s.points,
+                        rank: s.rank,
+                        created_at: s.created_at.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(StandingsHistoryResponse {
+            tournament_id,
+            standings,
+        })
+    }
+}
@@ -0,0 +1,215 @@
+use crate::handlers::league::request_models::CreateShotCallerPickRequest;
+use crate::handlers::league::response_models::{
+    LeaderboardEntry, SurvivorPickResponse, SurvivorStandingEntry, SurvivorStandingsResponse,
+};
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::survivor::SurvivorRepository;
+use sqlx::Error;
+use std::cmp::Ordering;
+
+pub struct SurvivorService;
+
+impl SurvivorService {
+    // A workout is decided once it is complete or a later workout has been locked.
+    async fn fetch_decided_ordinals(tournament_id: i64) -> Result<Vec<i64>, Error> {
+        let metadata = LeagueRepository::fetch_competition(tournament_id).await?;
+        let workouts = LeagueRepository::fetch_workouts(metadata.competition_id as i64).await?;
+
+        Ok(workouts
+            .iter()
+            .filter(|w| w.is_complete || (w.ordinal as u64) < metadata.locked_events)
+            .map(|w| w.ordinal)
+            .collect())
+    }
+
+    // Members whose pick scored lowest are eliminated, and a missing pick ranks below any score.
+    // When every remaining member ties for the lowest score nobody is eliminated.
+    async fn process_tournament_eliminations(tournament_id: i64) -> Result<(), Error> {
+        let decided_ordinals = Self::fetch_decided_ordinals(tournament_id).await?;
+        let members = LeagueRepository::fetch_tournament_users(tournament_id).await?;
+        let picks = SurvivorRepository::fetch_survivor_picks(tournament_id).await?;
+
+        SurvivorRepository::delete_survivor_eliminations(tournament_id).await?;
+
+        let mut alive: Vec<i64> = members
+            .iter()
+            .map(|m| m.tournament_user_id as i64)
+            .collect();
+
+        for ordinal in decided_ordinals {
+            if alive.len() <= 1 {
+                break;
+            }
+
+            let results: Vec<(i64, Option<f64>, f64)> = alive
+                .iter()
+                .map(|tournament_user_id| {
+                    let points = picks
+                        .iter()
+                        .find(|p| {
+                            p.tournament_user_id == *tournament_user_id && p.ordinal == ordinal
+                        })
+                        .map(|p| p.points.unwrap_or(0.0));
+
+                    (*tournament_user_id, points, points.unwrap_or(-1.0))
+                })
+                .collect();
+
+            let lowest = results.iter().map(|r| r.2).fold(f64::MAX, f64::min);
+            let eliminated: Vec<&(i64, Option<f64>, f64)> =
+                results.iter().filter(|r| r.2 == lowest).collect();
+
+            if eliminated.len() == alive.len() {
+                continue;
+            }
+
+            for (tournament_user_id, points, _) in eliminated.iter() {
+                SurvivorRepository::insert_survivor_elimination(
+                    tournament_id,
+                    *tournament_user_id,
+                    ordinal,
+                    *points,
+                )
+                .await?;
+            }
+
+            alive.retain(|tournament_user_id| {
+                !eliminated.iter().any(|r| r.0 == *tournament_user_id)
+            });
+        }
+
+        Ok(())
+    }
+
+    pub async fn process_eliminations(competition_id: i64) -> Result<(), Error> {
+        let tournament_ids =
+            SurvivorRepository::fetch_survivor_tournament_ids(competition_id).await?;
+
+        for tournament_id in tournament_ids {
+            Self::process_tournament_eliminations(tournament_id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn validate_pick(pick_request: &CreateShotCallerPickRequest) -> Result<(), Error> {
+        let is_eliminated =
+            SurvivorRepository::fetch_is_eliminated(pick_request.tournament_user_id).await?;
+
+        if is_eliminated {
+            return Err(Error::Protocol(
+                "Eliminated members can't make picks".to_string(),
+            ));
+        }
+
+        let used_competitor_ids = SurvivorRepository::fetch_used_competitor_ids(
+            pick_request.tournament_user_id,
+            pick_request.workout_id,
+        )
+        .await?;
+
+        if used_competitor_ids.contains(&pick_request.competitor_id) {
+            return Err(Error::Protocol(
+                "Competitor has already been used".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_standings(tournament_id: i64) -> Result<SurvivorStandingsResponse, Error> {
+        let decided_ordinals = Self::fetch_decided_ordinals(tournament_id).await?;
+        let members = LeagueRepository::fetch_tournament_users(tournament_id).await?;
+        let picks = SurvivorRepository::fetch_survivor_picks(tournament_id).await?;
+        let eliminations = SurvivorRepository::fetch_survivor_eliminations(tournament_id).await?;
+
+        let mut standings: Vec<SurvivorStandingEntry> = members
+            .iter()
+            .map(|m| {
+                let tournament_user_id = m.tournament_user_id as i64;
+                let eliminated_ordinal = eliminations
+                    .iter()
+                    .find(|e| e.tournament_user_id == tournament_user_id)
+                    .map(|e| e.ordinal);
+                let last_ordinal = eliminated_ordinal.unwrap_or(i64::MAX);
+
+                // Picks for workouts that aren't decided yet stay hidden from the standings.
+                let member_picks: Vec<SurvivorPickResponse> = picks
+                    .iter()
+                    .filter(|p| {
+                        p.tournament_user_id == tournament_user_id
+                            && decided_ordinals.contains(&p.ordinal)
+                    })
+                    .map(|p| SurvivorPickResponse {
+                        ordinal: p.ordinal,
+                        workout_id: p.workout_id,
+                        competitor_id: p.competitor_id,
+                        first_name: p.first_name.clone(),
+                        last_name: p.last_name.clone(),
+                        points: p.points.unwrap_or(0.0),
+                    })
+                    .collect();
+
+                SurvivorStandingEntry {
+                    tournament_user_id,
+                    display_name: m.display_name.clone(),
+                    avatar: m.avatar.clone(),
+                    is_alive: eliminated_ordinal.is_none(),
+                    eliminated_ordinal,
+                    events_survived: decided_ordinals
+                        .iter()
+                        .filter(|o| **o < last_ordinal)
+                        .count() as i64,
+                    points: member_picks.iter().map(|p| p.points).sum(),
+                    rank: 0,
+                    picks: member_picks,
+                }
+            })
+            .collect();
+
+        // Survivors rank first, then members who lasted longer, then total points.
+        let compare = |a: &SurvivorStandingEntry, b: &SurvivorStandingEntry| -> Ordering {
+            b.eliminated_ordinal
+                .unwrap_or(i64::MAX)
+                .cmp(&a.eliminated_ordinal.unwrap_or(i64::MAX))
+                .then(b.points.total_cmp(&a.points))
+        };
+
+        standings.sort_by(compare);
+
+        for i in 0..standings.len() {
+            standings[i].rank = if i > 0 && compare(&standings[i - 1], &standings[i]).is_eq() {
+                standings[i - 1].rank
+            } else {
+                i as i64 + 1
+            };
+        }
+
+        Ok(SurvivorStandingsResponse {
+            tournament_id,
+            alive_count: standings.iter().filter(|s| s.is_alive).count() as i64,
+            decided_events: decided_ordinals,
+            standings,
+        })
+    }
+
+    pub async fn get_survivor_leaderboard(
+        tournament_id: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        let standings = Self::get_standings(tournament_id).await?;
+
+        Ok(standings
+            .standings
+            .iter()
+            .map(|s| LeaderboardEntry {
+                tournament_user_id: s.tournament_user_id as u64,
+                display_name: s.display_name.clone(),
+                avatar: s.avatar.clone(),
+                points: s.points,
+                event_wins: s.events_survived,
+                ordinal: s.rank,
+                events: vec![],
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,462 @@
+use crate::data::constants::{draft_status, roster_move, tournament_type, waiver_status};
+use crate::data::models::tournament::Tournament;
+use crate::data::models::waiver_period::WaiverPeriod;
+use crate::handlers::draft::request_models::{RosterMoveRequest, WaiverClaimPathRequest};
+use crate::handlers::draft::response_models::{RosterEntry, WaiverResponse};
+use crate::handlers::league::response_models::{LeaderboardEntry, LeagueAthletesResponse};
+use crate::repositories::auction::AuctionRepository;
+use crate::repositories::draft::DraftRepository;
+use crate::repositories::league::LeagueRepository;
+use crate::repositories::roster::RosterRepository;
+use crate::repositories::waiver::WaiverRepository;
+use chrono::{Duration, Utc};
+use log::info;
+use sqlx::Error;
+
+pub(crate) const WAIVER_PERIOD_HOURS: i64 = 12;
+
+pub struct WaiverService;
+
+impl WaiverService {
+    fn is_draft_league(tournament_type_id: i64) -> bool {
+        tournament_type_id == tournament_type::SNAKE_DRAFT
+            || tournament_type_id == tournament_type::AUCTION_DRAFT
+    }
+
+    // Roster moves apply from the first workout that hasn't started yet.
+    async fn fetch_effective_ordinal(competition_id: i64) -> Result<Option<i64>, Error> {
+        let workouts = LeagueRepository::fetch_workouts(competition_id).await?;
+
+        Ok(workouts
+            .iter()
+            .find(|w| !w.is_active && !w.is_complete)
+            .map(|w| w.ordinal))
+    }
+
+    async fn fetch_free_agents(
+        competition_id: i64,
+        rosters: &[RosterEntry],
+    ) -> Result<Vec<LeagueAthletesResponse>, Error> {
+        let athletes = LeagueRepository::fetch_league_athletes(competition_id as u64).await?;
+
+        Ok(athletes
+            .into_iter()
+            .filter(|a| {
+                !a.is_withdrawn
+                    && !rosters
+                        .iter()
+                        .any(|r| r.competitor_id == a.competitor_id as i64)
+            })
+            .collect())
+    }
+
+    // Members without a priority yet are placed behind the existing order, worst standing first.
+    async fn seed_priority(tournament_id: i64, competition_id: i64) -> Result<(), Error> {
+        let priority = WaiverRepository::fetch_waiver_priority(tournament_id).await?;
+        let leaderboard =
+            RosterRepository::fetch_roster_leaderboard(tournament_id, competition_id).await?;
+
+        let mut missing: Vec<&LeaderboardEntry> = leaderboard
+            .iter()
+            .filter(|e| {
+                !priority
+                    .iter()
+                    .any(|p| p.tournament_user_id == e.tournament_user_id as i64)
+            })
+            .collect();
+
+        missing.sort_by(|a, b| {
+            b.ordinal
+                .cmp(&a.ordinal)
+                .then(a.tournament_user_id.cmp(&b.tournament_user_id))
+        });
+
+        for (next_priority, entry) in (priority.len() as i64 + 1..).zip(missing) {
+            WaiverRepository::upsert_waiver_priority(
+                tournament_id,
+                entry.tournament_user_id as i64,
+                next_priority,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_draft_tournament(
+        tournament_id: i64,
+        tournament_user_id: i64,
+    ) -> Result<Tournament, String> {
+        let tournament = LeagueRepository::fetch_tournament(tournament_id)
+            .await
+            .map_err(|_| "Unable to find league".to_string())?;
+
+        if !Self::is_draft_league(tournament.tournament_type_id as i64) {
+            return Err("Waivers are only available in draft leagues".to_string());
+        }
+
+        let member = LeagueRepository::fetch_tournament_member(tournament_user_id)
+            .await
+            .map_err(|_| "Unable to find league member".to_string())?;
+
+        if member.tournament_id != tournament_id {
+            return Err("User is not a member of this league".to_string());
+        }
+
+        Ok(tournament)
+    }
+
+    async fn validate_roster_move(
+        tournament: &Tournament,
+        move_request: &RosterMoveRequest,
+    ) -> Result<i64, String> {
+        let event_status =
+            LeagueRepository::fetch_competition_tournament_status(move_request.tournament_user_id)
+                .await
+                .map_err(|_| "Unable to get event details".to_string())?;
+
+        if event_status.is_complete {
+            return Err("Can't update rosters for a complete competition".to_string());
+        }
+
+        // Pickups during the draft or auction would let another member take the same athlete.
+        let is_snake_draft = tournament.tournament_type_id as i64 == tournament_type::SNAKE_DRAFT;
+        let roster_status = if is_snake_draft {
+            DraftRepository::fetch_draft(tournament.id as i64)
+                .await
+                .map(|d| d.status)
+        } else {
+            AuctionRepository::fetch_auction(tournament.id as i64)
+                .await
+                .map(|a| a.status)
+        }
+        .map_err(|_| "Unable to get draft status".to_string())?;
+
+        if roster_status != draft_status::COMPLETE {
+            return Err("Rosters can't change until the draft is complete".to_string());
+        }
+
+        let competition_id = tournament.competition_id as i64;
+
+        let Some(effective_ordinal) = Self::fetch_effective_ordinal(competition_id)
+            .await
+            .map_err(|_| "Unable to get workouts".to_string())?
+        else {
+            return Err("No remaining workouts to update rosters for".to_string());
+        };
+
+        let rosters = RosterRepository::fetch_rosters(tournament.id as i64)
+            .await
+            .map_err(|_| "Unable to get rosters".to_string())?;
+
+        let Some(dropped) = rosters.iter().find(|r| {
+            r.tournament_user_id == move_request.tournament_user_id
+                && r.competitor_id == move_request.drop_competitor_id
+        }) else {
+            return Err("Competitor is not on your roster".to_string());
+        };
+
+        if rosters
+            .iter()
+            .any(|r| r.competitor_id == move_request.add_competitor_id)
+        {
+            return Err("Competitor is already on a roster".to_string());
+        }
+
+        let athletes = LeagueRepository::fetch_league_athletes(competition_id as u64)
+            .await
+            .map_err(|_| "Unable to get athletes".to_string())?;
+
+        let Some(added) = athletes
+            .iter()
+            .find(|a| a.competitor_id as i64 == move_request.add_competitor_id)
+        else {
+            return Err("Competitor is not in this competition".to_string());
+        };
+
+        if added.is_withdrawn {
+            return Err("Competitor has withdrawn".to_string());
+        }
+
+        if added.gender_id as i64 != dropped.gender_id {
+            return Err("Dropped and added competitors must be the same gender".to_string());
+        }
+
+        if tournament.tournament_type_id as i64 == tournament_type::AUCTION_DRAFT
+            && added.position_id != dropped.position_id
+        {
+            return Err(
+                "Added competitor must fit the dropped competitor's roster slot".to_string(),
+            );
+        }
+
+        Ok(effective_ordinal)
+    }
+
+    async fn process_tournament_claims(
+        tournament_id: i64,
+        competition_id: i64,
+    ) -> Result<(), Error> {
+        let mut claims = WaiverRepository::fetch_pending_waiver_claims(tournament_id).await?;
+
+        if claims.is_empty() {
+            return Ok(());
+        }
+
+        let Some(effective_ordinal) = Self::fetch_effective_ordinal(competition_id).await? else {
+            for claim in claims {
+                WaiverRepository::update_waiver_claim_status(claim.id, waiver_status::FAILED)
+                    .await?;
+            }
+
+            return Ok(());
+        };
+
+        Self::seed_priority(tournament_id, competition_id).await?;
+
+        let mut priority: Vec<i64> = WaiverRepository::fetch_waiver_priority(tournament_id)
+            .await?
+            .iter()
+            .map(|p| p.tournament_user_id)
+            .collect();
+        let mut rosters = RosterRepository::fetch_rosters(tournament_id).await?;
+
+        // The highest priority member with a still valid claim wins it and drops to the back.
+        loop {
+            let awarded = priority.iter().find_map(|tournament_user_id| {
+                claims.iter().find(|c| {
+                    c.tournament_user_id == *tournament_user_id
+                        && rosters.iter().any(|r| {
+                            r.tournament_user_id == c.tournament_user_id
+                                && r.competitor_id == c.drop_competitor_id
+                        })
+                        && !rosters
+                            .iter()
+                            .any(|r| r.competitor_id == c.add_competitor_id)
+                })
+            });
+
+            let Some(claim) = awarded.cloned() else {
+                break;
+            };
+
+            info!(
+                "process_tournament_claims: tournament {} awarded claim {}",
+                tournament_id, claim.id
+            );
+
+            let is_awarded = RosterRepository::insert_roster_move(
+                tournament_id,
+                claim.tournament_user_id,
+                claim.drop_competitor_id,
+                claim.add_competitor_id,
+                effective_ordinal,
+                roster_move::WAIVER,
+                Some(claim.id),
+            )
+            .await?;
+
+            if is_awarded {
+                priority.retain(|p| *p != claim.tournament_user_id);
+                priority.push(claim.tournament_user_id);
+            } else {
+                // A pickup or an overlapping run changed the rosters since they were loaded.
+                WaiverRepository::update_waiver_claim_status(claim.id, waiver_status::FAILED)
+                    .await?;
+            }
+
+            claims.retain(|c| c.id != claim.id);
+            rosters = RosterRepository::fetch_rosters(tournament_id).await?;
+        }
+
+        for claim in claims {
+            WaiverRepository::update_waiver_claim_status(claim.id, waiver_status::FAILED).await?;
+        }
+
+        for (i, tournament_user_id) in priority.iter().enumerate() {
+            WaiverRepository::upsert_waiver_priority(
+                tournament_id,
+                *tournament_user_id,
+                i as i64 + 1,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    // The period is only marked processed once every claim is settled, so a failed run is picked
+    // up again by the next one. Awards lock the league's rosters and only settle pending claims,
+    // so overlapping runs can't award a claim or a competitor twice.
+    async fn process_waiver_period(period: &WaiverPeriod) -> Result<(), Error> {
+        let tournament_ids =
+            WaiverRepository::fetch_waiver_tournament_ids(period.competition_id).await?;
+
+        for tournament_id in tournament_ids {
+            Self::process_tournament_claims(tournament_id, period.competition_id).await?;
+        }
+
+        WaiverRepository::update_waiver_period_processed(period.id).await?;
+
+        Ok(())
+    }
+
+    // Processes a waiver period whose deadline has passed before the cron gets to it. Only
+    // claims and pickups go through here, reads leave it to the waiver job.
+    async fn fetch_current_period(competition_id: i64) -> Result<Option<WaiverPeriod>, Error> {
+        let period = WaiverRepository::fetch_open_waiver_period(competition_id).await?;
+
+        match period {
+            Some(due) if due.process_at < Utc::now() => {
+                Self::process_waiver_period(&due).await?;
+
+                WaiverRepository::fetch_open_waiver_period(competition_id).await
+            }
+            _ => Ok(period),
+        }
+    }
+
+    pub async fn open_waiver_period(competition_id: i64, ordinal: i64) -> Result<(), Error> {
+        let tournament_ids = WaiverRepository::fetch_waiver_tournament_ids(competition_id).await?;
+
+        if tournament_ids.is_empty() {
+            return Ok(());
+        }
+
+        WaiverRepository::insert_waiver_period(
+            competition_id,
+            ordinal,
+            Utc::now() + Duration::hours(WAIVER_PERIOD_HOURS),
+        )
+        .await?;
+
+        for tournament_id in tournament_ids {
+            Self::seed_priority(tournament_id, competition_id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn process_waivers() -> Result<(), Error> {
+        let periods = WaiverRepository::fetch_due_waiver_periods().await?;
+
+        for period in periods {
+            Self::process_waiver_period(&period).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_waivers(tournament_id: i64) -> Result<WaiverResponse, Error> {
+        let tournament = LeagueRepository::fetch_tournament(tournament_id).await?;
+        let competition_id = tournament.competition_id as i64;
+
+        let period = WaiverRepository::fetch_open_waiver_period(competition_id).await?;
+        let rosters = RosterRepository::fetch_rosters(tournament_id).await?;
+
+        Ok(WaiverResponse {
+            tournament_id,
+            is_waiver_period: period.is_some(),
+            process_at: period.map(|p| p.process_at.to_rfc3339()),
+            effective_ordinal: Self::fetch_effective_ordinal(competition_id).await?,
+            priority: WaiverRepository::fetch_waiver_priority(tournament_id).await?,
+            claims: WaiverRepository::fetch_waiver_claims(tournament_id).await?,
+            free_agents: Self::fetch_free_agents(competition_id, &rosters).await?,
+            rosters,
+        })
+    }
+
+    pub async fn submit_claim(
+        tournament_id: i64,
+        claim: &RosterMoveRequest,
+    ) -> Result<WaiverResponse, String> {
+        let tournament =
+            Self::fetch_draft_tournament(tournament_id, claim.tournament_user_id).await?;
+
+        let period = Self::fetch_current_period(tournament.competition_id as i64)
+            .await
+            .map_err(|_| "Unable to get waiver period".to_string())?;
+
+        if period.is_none() {
+            return Err("Waivers are closed, pick up free agents instead".to_string());
+        }
+
+        Self::validate_roster_move(&tournament, claim).await?;
+
+        WaiverRepository::insert_waiver_claim(
+            tournament_id,
+            claim.tournament_user_id,
+            claim.drop_competitor_id,
+            claim.add_competitor_id,
+        )
+        .await
+        .map_err(|_| "Unable to save waiver claim".to_string())?;
+
+        Self::get_waivers(tournament_id)
+            .await
+            .map_err(|_| "Unable to get waivers".to_string())
+    }
+
+    pub async fn cancel_claim(path: &WaiverClaimPathRequest) -> Result<WaiverResponse, String> {
+        let claim = WaiverRepository::fetch_waiver_claim(path.claim_id)
+            .await
+            .map_err(|_| "Unable to find waiver claim".to_string())?;
+
+        if claim.tournament_user_id != path.tournament_user_id {
+            return Err("Waiver claim belongs to another member".to_string());
+        }
+
+        if claim.status != waiver_status::PENDING {
+            return Err("Waiver claim has already been processed".to_string());
+        }
+
+        WaiverRepository::update_waiver_claim_status(claim.id, waiver_status::CANCELLED)
+            .await
+            .map_err(|_| "Unable to cancel waiver claim".to_string())?;
+
+        Self::get_waivers(claim.tournament_id)
+            .await
+            .map_err(|_| "Unable to get waivers".to_string())
+    }
+
+    pub async fn pick_up_free_agent(
+        tournament_id: i64,
+        pickup: &RosterMoveRequest,
+    ) -> Result<WaiverResponse, String> {
+        let tournament =
+            Self::fetch_draft_tournament(tournament_id, pickup.tournament_user_id).await?;
+
+        let period = Self::fetch_current_period(tournament.competition_id as i64)
+            .await
+            .map_err(|_| "Unable to get waiver period".to_string())?;
+
+        if let Some(period) = period {
+            return Err(format!(
+                "Competitors are on waivers until {}",
+                period.process_at.to_rfc3339()
+            ));
+        }
+
+        let effective_ordinal = Self::validate_roster_move(&tournament, pickup).await?;
+
+        let is_picked_up = RosterRepository::insert_roster_move(
+            tournament_id,
+            pickup.tournament_user_id,
+            pickup.drop_competitor_id,
+            pickup.add_competitor_id,
+            effective_ordinal,
+            roster_move::FREE_AGENT,
+            None,
+        )
+        .await
+        .map_err(|_| "Unable to save roster move".to_string())?;
+
+        if !is_picked_up {
+            return Err("Competitor is no longer available".to_string());
+        }
+
+        Self::get_waivers(tournament_id)
+            .await
+            .map_err(|_| "Unable to get waivers".to_string())
+    }
+}
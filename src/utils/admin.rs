@@ -0,0 +1,18 @@
+use crate::data::constants::admin;
+use actix_web::HttpRequest;
+use std::env;
+
+// Admin requests carry the shared secret in a header. Without a configured secret every
+// request is refused.
+pub(crate) fn is_admin(req: &HttpRequest) -> bool {
+    let Ok(secret) = env::var(admin::SECRET) else {
+        return false;
+    };
+
+    !secret.is_empty()
+        && req
+            .headers()
+            .get(admin::HEADER)
+            .and_then(|h| h.to_str().ok())
+            == Some(secret.as_str())
+}
@@ -1 +1,2 @@
-pub mod notification;
\ No newline at end of file
+pub mod admin;
+pub mod notification;